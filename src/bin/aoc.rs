@@ -0,0 +1,48 @@
+use std::env;
+use std::process::ExitCode;
+
+use advent_of_code_2024::runner;
+use advent_of_code_2024::runner::args::{self, Command};
+
+fn main() -> ExitCode {
+    match args::parse(env::args().skip(1)) {
+        Ok(Command::Run {
+            year,
+            days,
+            parts,
+            params,
+            mode,
+            json,
+        }) => runner::run(year, &days, &parts, &params, mode, json.as_deref()),
+        Ok(Command::Bench {
+            year,
+            days,
+            parts,
+            params,
+            options,
+            baseline,
+        }) => {
+            let tally = runner::bench(year, &days, &parts, &params, options);
+
+            let exit_code = runner::compare_and_save(&tally.benchmarks, &baseline);
+
+            if tally.errors > 0 {
+                ExitCode::FAILURE
+            } else {
+                exit_code
+            }
+        },
+        Ok(Command::List { year }) => runner::list(year),
+        Ok(Command::New(new_day)) => runner::new(&new_day),
+        Ok(Command::Help) => {
+            println!("{}", args::USAGE);
+
+            ExitCode::SUCCESS
+        },
+        Err(error) => {
+            eprintln!("error: {}\n\n{}", error, args::USAGE);
+
+            ExitCode::FAILURE
+        },
+    }
+}
@@ -1 +1,84 @@
+pub mod day_01;
+pub mod day_02;
+pub mod day_03;
+pub mod day_04;
+pub mod day_05;
+pub mod day_06;
+pub mod day_07;
+pub mod day_08;
+pub mod day_09;
+pub mod day_10;
+pub mod day_11;
+pub mod day_12;
+pub mod day_13;
+pub mod day_14;
+pub mod day_15;
+pub mod day_16;
+pub mod day_17;
+pub mod day_18;
+pub mod day_19;
+pub mod day_20;
+pub mod day_21;
+pub mod day_22;
+pub mod day_23;
+pub mod day_24;
+pub mod day_25;
 
+use crate::puzzle;
+use crate::runner::solver::Solver;
+use crate::shared::metadata::Metadata;
+use crate::shared::puzzle::PuzzleId;
+
+/// Every solved puzzle, in order, with its metadata.
+const SOLUTIONS: [(PuzzleId, &dyn Solver, &Metadata); 25] = [
+    (puzzle!(2024, 1), &day_01::Solution {}, &day_01::METADATA),
+    (puzzle!(2024, 2), &day_02::Solution {}, &day_02::METADATA),
+    (puzzle!(2024, 3), &day_03::Solution {}, &day_03::METADATA),
+    (puzzle!(2024, 4), &day_04::Solution {}, &day_04::METADATA),
+    (puzzle!(2024, 5), &day_05::Solution {}, &day_05::METADATA),
+    (puzzle!(2024, 6), &day_06::Solution {}, &day_06::METADATA),
+    (puzzle!(2024, 7), &day_07::Solution {}, &day_07::METADATA),
+    (puzzle!(2024, 8), &day_08::Solution {}, &day_08::METADATA),
+    (puzzle!(2024, 9), &day_09::Solution {}, &day_09::METADATA),
+    (puzzle!(2024, 10), &day_10::Solution {}, &day_10::METADATA),
+    (puzzle!(2024, 11), &day_11::Solution {}, &day_11::METADATA),
+    (puzzle!(2024, 12), &day_12::Solution {}, &day_12::METADATA),
+    (puzzle!(2024, 13), &day_13::Solution {}, &day_13::METADATA),
+    (puzzle!(2024, 14), &day_14::Solution {}, &day_14::METADATA),
+    (puzzle!(2024, 15), &day_15::Solution {}, &day_15::METADATA),
+    (puzzle!(2024, 16), &day_16::Solution {}, &day_16::METADATA),
+    (puzzle!(2024, 17), &day_17::Solution {}, &day_17::METADATA),
+    (puzzle!(2024, 18), &day_18::Solution {}, &day_18::METADATA),
+    (puzzle!(2024, 19), &day_19::Solution {}, &day_19::METADATA),
+    (puzzle!(2024, 20), &day_20::Solution {}, &day_20::METADATA),
+    (puzzle!(2024, 21), &day_21::Solution {}, &day_21::METADATA),
+    (puzzle!(2024, 22), &day_22::Solution {}, &day_22::METADATA),
+    (puzzle!(2024, 23), &day_23::Solution {}, &day_23::METADATA),
+    (puzzle!(2024, 24), &day_24::Solution {}, &day_24::METADATA),
+    (puzzle!(2024, 25), &day_25::Solution {}, &day_25::METADATA),
+];
+
+/// Gets the solution registered for `puzzle`, if any.
+#[must_use]
+pub fn get(puzzle: PuzzleId) -> Option<&'static dyn Solver> {
+    SOLUTIONS
+        .iter()
+        .find(|&&(registered, _, _)| registered == puzzle)
+        .map(|&(_, solution, _)| solution)
+}
+
+/// Gets the metadata of the solution registered for `puzzle`, if any.
+#[must_use]
+pub fn metadata(puzzle: PuzzleId) -> Option<&'static Metadata> {
+    SOLUTIONS
+        .iter()
+        .find(|&&(registered, _, _)| registered == puzzle)
+        .map(|&(_, _, metadata)| metadata)
+}
+
+/// Iterates over all registered solutions, in order of their [`PuzzleId`].
+pub fn all() -> impl Iterator<Item = (PuzzleId, &'static dyn Solver)> {
+    SOLUTIONS
+        .iter()
+        .map(|&(puzzle, solution, _)| (puzzle, solution))
+}
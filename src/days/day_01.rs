@@ -0,0 +1,69 @@
+use hashbrown::HashMap;
+
+use crate::shared::{PartSolution, Parts};
+
+crate::solution!(title: "Historian Hysteria", complexity: "O(n log n)");
+
+fn calculate_distances(input: &str) -> PartSolution {
+    let lines = input.lines().collect::<Vec<&str>>();
+
+    let mut left = Vec::with_capacity(lines.len());
+    let mut right = Vec::with_capacity(lines.len());
+
+    for line in lines {
+        let (l, r) = line
+            .split_once(' ')
+            .map(|(l, r)| (l.trim().parse::<u32>(), r.trim().parse::<u32>()))
+            .expect("Bad input");
+
+        left.push(l.expect("Bad input"));
+        right.push(r.expect("Bad input"));
+    }
+
+    // we don't need to sort smallest to largest for now
+    left.sort_unstable();
+    right.sort_unstable();
+
+    let mut total_diff = 0;
+
+    for (l, r) in left.into_iter().zip(right) {
+        total_diff += l.abs_diff(r);
+    }
+
+    PartSolution::U32(total_diff)
+}
+
+fn calculate_similarity(input: &str) -> PartSolution {
+    let mut map_l: HashMap<u32, u32> = HashMap::new();
+    let mut map_r: HashMap<u32, u32> = HashMap::new();
+
+    for line in input.lines() {
+        let (l, r) = line
+            .split_once(' ')
+            .map(|(l, r)| (l.trim().parse::<u32>(), r.trim().parse::<u32>()))
+            .expect("Bad input");
+
+        let (l, r) = (l.expect("Bad input"), r.expect("Bad input"));
+
+        map_l.entry(l).and_modify(|v| *v += 1).or_insert(1);
+        map_r.entry(r).and_modify(|v| *v += 1).or_insert(1);
+    }
+
+    let mut similiarity = 0;
+
+    for (number, count) in map_l {
+        similiarity += number * count * map_r.get(&number).copied().unwrap_or(0);
+    }
+
+    PartSolution::U32(similiarity)
+}
+
+impl Parts for Solution {
+    fn part_1(&self, input: &str) -> PartSolution {
+        calculate_distances(input)
+    }
+
+    fn part_2(&self, input: &str) -> PartSolution {
+        calculate_similarity(input)
+    }
+}
@@ -0,0 +1,103 @@
+use crate::shared::grids::grid::Grid;
+use crate::shared::grids::point::{Point, Vector};
+use crate::shared::grids::{GridIter as _, HorizontalVerticalDiagonalDirection, Neighbors as _};
+use crate::shared::{PartSolution, Parts};
+
+crate::solution!(title: "Ceres Search", tags: [Grids], uses: [grids]);
+
+fn count_xmas(input: &str) -> PartSolution {
+    let g: Grid<char> = input.parse().expect("Bad input");
+
+    let mut solution = 0;
+
+    for ((row_index, column_index), c) in g.row_column_index_value_iter() {
+        if 'X' == *c {
+            // good starting point, trace neighbors
+            let neighbors = g.hvd_neighbors(row_index, column_index);
+
+            for ((_, _), direction) in neighbors {
+                if is_merry(
+                    &g,
+                    (row_index, column_index).into(),
+                    direction,
+                    &['M', 'A', 'S'],
+                ) {
+                    solution += 1;
+                }
+            }
+        }
+    }
+
+    PartSolution::U32(solution)
+}
+
+fn is_merry(
+    grid: &Grid<char>,
+    point: Point,
+    direction: HorizontalVerticalDiagonalDirection,
+    to_match: &[char],
+) -> bool {
+    let Some((first, rest)) = to_match.split_first() else {
+        return true;
+    };
+
+    grid.neighbor(point, direction)
+        .is_some_and(|next| grid[next] == *first && is_merry(grid, next, direction, rest))
+}
+
+fn count_max_as_x(input: &str) -> PartSolution {
+    let g: Grid<char> = input.parse().expect("Bad input");
+
+    let mut solution = 0;
+
+    for ((row_index, column_index), c) in g.row_column_index_value_iter() {
+        if 'A' == *c {
+            let center = Point::new(row_index, column_index);
+
+            // we can have
+            // M M or S M or S S or M S
+            //  A      A      A      A
+            // S S    S M    M M    M S
+
+            // clockwise, from left, top
+            let to_check = [(-1, -1), (-1, 1), (1, 1), (1, -1)];
+
+            // clockwise, from left, top
+            let mut expected = ['M', 'M', 'S', 'S'];
+
+            for _ in 0..expected.len() {
+                let mut matches = 0;
+
+                for (&(mod_x, mod_y), expected_value) in to_check.iter().zip(expected) {
+                    if let Some(&value) = g.get_offset(center, Vector::new(mod_x, mod_y)) {
+                        // there is a value there
+                        if value == expected_value {
+                            matches += 1;
+                            // ... go on
+                        }
+                    } else {
+                        break;
+                    }
+                }
+
+                if matches == expected.len() {
+                    solution += 1;
+                }
+
+                expected.rotate_right(1);
+            }
+        }
+    }
+
+    PartSolution::U32(solution)
+}
+
+impl Parts for Solution {
+    fn part_1(&self, input: &str) -> PartSolution {
+        count_xmas(input)
+    }
+
+    fn part_2(&self, input: &str) -> PartSolution {
+        count_max_as_x(input)
+    }
+}
@@ -0,0 +1,238 @@
+use hashbrown::HashSet;
+
+use crate::shared::grids::grid::Grid;
+use crate::shared::grids::point::Point;
+use crate::shared::grids::{GridIter as _, HorizontalVerticalDirection as Direction};
+use crate::shared::{PartSolution, Parts};
+
+crate::solution!(title: "Guard Gallivant", tags: [Grids, Simulation], uses: [grids]);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Open,
+    Obstruction,
+    Guard(Direction),
+}
+
+impl std::fmt::Debug for Cell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::Open => write!(f, "."),
+            Self::Obstruction => write!(f, "#"),
+            Self::Guard(d) => {
+                let guard = match d {
+                    Direction::Up => '^',
+                    Direction::Down => 'v',
+                    Direction::Left => '<',
+                    Direction::Right => '>',
+                };
+
+                write!(f, "{}", guard)
+            },
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Grid<Cell> {
+    Grid::parse(input, |c| match c {
+        '.' => Ok(Cell::Open),
+        '#' => Ok(Cell::Obstruction),
+        '^' => Ok(Cell::Guard(Direction::Up)),
+        _ => Err("expected one of `.`, `#` or `^`"),
+    })
+    .expect("Bad input")
+}
+
+fn count_guard_positions(input: &str) -> PartSolution {
+    let mut grid = parse_input(input);
+    let mut traveled = HashSet::<(usize, usize)>::new();
+    // starting direction is always up
+    let mut direction = Direction::Up;
+    let (mut guard_row_index, mut guard_column_index) = grid
+        .row_column_index_value_iter()
+        .find(|c| c == &Cell::Guard(direction))
+        .expect("Bad input");
+
+    // starting position needs to be recorded too
+    traveled.insert((guard_row_index, guard_column_index));
+
+    while let Some((r, c, d)) = travel(&mut grid, guard_row_index, guard_column_index, direction) {
+        guard_row_index = r;
+        guard_column_index = c;
+        direction = d;
+
+        traveled.insert((guard_row_index, guard_column_index));
+    }
+
+    traveled.len().into()
+}
+
+fn travel(
+    grid: &mut Grid<Cell>,
+    guard_row_index: usize,
+    guard_column_index: usize,
+    direction: Direction,
+) -> Option<(usize, usize, Direction)> {
+    // guard is at (guard_row_index, guard_column_index) and facing direction
+
+    let guard = Point::new(guard_row_index, guard_column_index);
+
+    // unset the current space, we're moving
+    grid[guard] = Cell::Open;
+
+    // calculate the next position. If out of bounds, return none
+    // if empty, move, update grid and return
+    // if obstacle, rotate, invoke self
+    let new_guard = grid.neighbor(guard, direction)?;
+
+    if matches!(grid[new_guard], Cell::Obstruction) {
+        let new_direction = direction.turn_right();
+
+        // can't move in that direction, travel again, but pointing in new direction
+        grid[guard] = Cell::Guard(new_direction);
+        return travel(grid, guard_row_index, guard_column_index, new_direction);
+    }
+
+    // shortcut, cell is empty, return new position, maintain direction
+    grid[new_guard] = Cell::Guard(direction);
+    Some((new_guard.row_index, new_guard.column_index, direction))
+}
+
+struct PathTraveled {
+    traveled: Vec<(usize, usize)>,
+    direction: Direction,
+    new_direction: Direction,
+}
+
+fn travel_trace(
+    grid: &mut Grid<Cell>,
+    guard_row_index: usize,
+    guard_column_index: usize,
+    direction: Direction,
+) -> Option<PathTraveled> {
+    // guard is at (guard_row_index, guard_column_index) and facing direction
+
+    let guard = Point::new(guard_row_index, guard_column_index);
+
+    // unset the current space, we're moving
+    grid[guard] = Cell::Open;
+
+    let mut traveled = Vec::new();
+
+    // move until we're out of bounds
+    // if empty, move, update grid and continue
+    // if obstacle, rotate and return the path so far
+    let mut new_guard = guard;
+
+    while let Some(next) = grid.neighbor(new_guard, direction) {
+        new_guard = next;
+
+        if matches!(grid[new_guard], Cell::Obstruction) {
+            let new_direction = direction.turn_right();
+
+            // can't move in that direction, travel again, but pointing in new direction
+            grid[guard] = Cell::Guard(new_direction);
+            return Some(PathTraveled {
+                traveled,
+                direction,
+                new_direction,
+            });
+        }
+
+        // shortcut, cell is empty, maintain direction
+        grid[new_guard] = Cell::Guard(direction);
+        traveled.push(new_guard.into());
+    }
+
+    // OOB
+    None
+}
+
+fn count_possible_obstacle_positions(input: &str) -> PartSolution {
+    let mut grid = parse_input(input);
+
+    let original = grid.clone();
+
+    // starting direction is always up
+    let mut direction = Direction::Up;
+
+    let (mut guard_row_index, mut guard_column_index) = grid
+        .row_column_index_value_iter()
+        .find(|c| c == &Cell::Guard(direction))
+        .expect("Bad input");
+
+    let (start_guard_row_index, start_guard_column_index) = (guard_row_index, guard_column_index);
+
+    let mut tried_infinite = HashSet::new();
+
+    let mut infinite = 0_usize;
+
+    while let Some((r, c, d)) = travel(&mut grid, guard_row_index, guard_column_index, direction) {
+        guard_row_index = r;
+        guard_column_index = c;
+        direction = d;
+
+        // we can now put an obstacle here, restart and see if it's an endless loop
+        // one optimization is that we can check whether, once turned (i.e. direction) it abandons the board
+        if (guard_row_index, guard_column_index)
+            != (start_guard_row_index, start_guard_column_index)
+            && tried_infinite.insert((guard_row_index, guard_column_index))
+        {
+            // we can put an obstacle here
+            let mut new_grid = original.clone();
+            new_grid[guard_row_index][guard_column_index] = Cell::Obstruction;
+
+            if is_infinite(new_grid, start_guard_row_index, start_guard_column_index) {
+                infinite += 1;
+            }
+        }
+    }
+
+    infinite.into()
+}
+
+fn is_infinite(
+    mut grid: Grid<Cell>,
+    mut guard_row_index: usize,
+    mut guard_column_index: usize,
+) -> bool {
+    let mut coordinates_visited = HashSet::<(usize, usize, Direction)>::new();
+
+    // starting direction is always up
+    let mut direction = Direction::Up;
+
+    // starting position needs to be recorded too
+    coordinates_visited.insert((guard_row_index, guard_column_index, direction));
+
+    while let Some(PathTraveled {
+        traveled,
+        direction: old_direction,
+        new_direction,
+    }) = travel_trace(&mut grid, guard_row_index, guard_column_index, direction)
+    {
+        coordinates_visited.reserve(traveled.len());
+        for (r, c) in traveled {
+            // if we have been at this spot, in this direction it's infinite
+            if !coordinates_visited.insert((r, c, old_direction)) {
+                return true;
+            }
+
+            guard_row_index = r;
+            guard_column_index = c;
+        }
+
+        direction = new_direction;
+    }
+
+    false
+}
+
+impl Parts for Solution {
+    fn part_1(&self, input: &str) -> PartSolution {
+        count_guard_positions(input)
+    }
+
+    fn part_2(&self, input: &str) -> PartSolution {
+        count_possible_obstacle_positions(input)
+    }
+}
@@ -0,0 +1,126 @@
+use hashbrown::{HashMap, HashSet};
+
+use crate::shared::grids::GridIter as _;
+use crate::shared::grids::grid::Grid;
+use crate::shared::grids::point::Point;
+use crate::shared::{PartSolution, Parts};
+
+crate::solution!(title: "Resonant Collinearity", tags: [Grids], uses: [grids]);
+
+#[derive(PartialEq, Eq)]
+enum Cell {
+    Antenna(char),
+    Nothing,
+}
+
+impl From<char> for Cell {
+    fn from(value: char) -> Self {
+        match value {
+            '.' => Cell::Nothing,
+            v => Cell::Antenna(v),
+        }
+    }
+}
+
+impl std::fmt::Display for Cell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Cell::Antenna(c) => write!(f, "{}", c),
+            Cell::Nothing => write!(f, "."),
+        }
+    }
+}
+
+struct Parsed {
+    grid: Grid<Cell>,
+    antennas: HashMap<char, Vec<Point>>,
+}
+
+fn parse_into_grid_and_group_antennas(input: &str) -> Parsed {
+    let grid: Grid<Cell> = input.parse().expect("Bad input");
+
+    let mut antennas: HashMap<char, Vec<Point>> = HashMap::new();
+
+    for ((row_index, column_index), cell) in grid.row_column_index_value_iter() {
+        if let &Cell::Antenna(c) = cell {
+            antennas
+                .entry(c)
+                .and_modify(|v| {
+                    v.push(Point::new(row_index, column_index));
+                })
+                .or_insert(vec![Point::new(row_index, column_index)]);
+        }
+    }
+
+    Parsed { grid, antennas }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy)]
+enum Times {
+    Once,
+    Infinite,
+}
+
+fn count_antinodes(input: &str, times: Times) -> PartSolution {
+    let Parsed { grid, antennas } = parse_into_grid_and_group_antennas(input);
+
+    let mut antinodes = HashSet::new();
+
+    for (_key, value) in &antennas {
+        let permutations = permutations(value);
+
+        for (mut antenna_1, mut antenna_2) in permutations {
+            if times == Times::Infinite {
+                // when infinite we count ourselves
+                antinodes.insert(antenna_1);
+                antinodes.insert(antenna_2);
+            }
+
+            let difference = antenna_1.checked_sub(antenna_2).expect("OOB");
+
+            // apply the difference to antenna 1 to get antinode 1
+            while let Some(antinode) = grid.offset(antenna_1, difference) {
+                antinodes.insert(antinode);
+                antenna_1 = antinode;
+
+                if times == Times::Once {
+                    break;
+                }
+            }
+
+            // we now apply the opposite of the difference to antenna 2 to get antinode 2
+            while let Some(antinode) = grid.offset(antenna_2, -difference) {
+                antinodes.insert(antinode);
+                antenna_2 = antinode;
+
+                if times == Times::Once {
+                    break;
+                }
+            }
+        }
+    }
+
+    antinodes.len().into()
+}
+
+fn permutations(v: &[Point]) -> Vec<(Point, Point)> {
+    let mut permutations = vec![];
+
+    for (outer_index, outer_value) in v.iter().enumerate() {
+        for inner_value in v.iter().skip(outer_index + 1) {
+            permutations.push((*outer_value, *inner_value));
+        }
+    }
+
+    permutations
+}
+
+impl Parts for Solution {
+    fn part_1(&self, input: &str) -> PartSolution {
+        count_antinodes(input, Times::Once)
+    }
+
+    fn part_2(&self, input: &str) -> PartSolution {
+        count_antinodes(input, Times::Infinite)
+    }
+}
@@ -0,0 +1,122 @@
+use hashbrown::HashSet;
+
+use crate::shared::grids::grid::Grid;
+use crate::shared::grids::{GridIter as _, Neighbors as _};
+use crate::shared::{PartSolution, Parts};
+
+crate::solution!(title: "Hoof It", tags: [Grids, Pathfinding], uses: [grids]);
+
+#[derive(PartialEq, Eq)]
+enum Cell {
+    Number(u32),
+    Empty,
+}
+
+impl From<char> for Cell {
+    fn from(value: char) -> Self {
+        value.to_digit(10).map_or(Cell::Empty, Cell::Number)
+    }
+}
+
+fn parse_input(input: &str) -> Grid<Cell> {
+    input.parse().expect("Bad input")
+}
+
+fn count_longest_paths(input: &str) -> PartSolution {
+    let grid = parse_input(input);
+
+    let starts = grid
+        .row_column_index_value_iter()
+        .filter_map(|((row_index, column_index), value)| match value {
+            &Cell::Number(0) => Some((row_index, column_index)),
+            &Cell::Number(_) | &Cell::Empty => None,
+        })
+        .collect::<Vec<(usize, usize)>>();
+
+    let mut completed_paths = 0;
+
+    for (row_index, column_index) in starts {
+        let mut got_to_nine = HashSet::new();
+        follow_0_to_9(&grid, row_index, column_index, 0, &mut got_to_nine);
+
+        completed_paths += got_to_nine.len();
+    }
+
+    completed_paths.into()
+}
+
+fn follow_0_to_9(
+    grid: &Grid<Cell>,
+    row_index: usize,
+    column_index: usize,
+    number: u32,
+    got_to_nine_coordinates: &mut HashSet<(usize, usize)>,
+) {
+    if number == 9 {
+        got_to_nine_coordinates.insert((row_index, column_index));
+        return;
+    }
+
+    for ((neighbor_row_index, neighbor_column_index), _) in
+        grid.hv_neighbors_where(row_index, column_index, |cell| {
+            *cell == Cell::Number(number + 1)
+        })
+    {
+        follow_0_to_9(
+            grid,
+            neighbor_row_index,
+            neighbor_column_index,
+            number + 1,
+            got_to_nine_coordinates,
+        );
+    }
+}
+
+fn follow_all_paths(input: &str) -> PartSolution {
+    let grid = parse_input(input);
+
+    let starts = grid
+        .row_column_index_value_iter()
+        .filter_map(|((row_index, column_index), value)| match value {
+            &Cell::Number(0) => Some((row_index, column_index)),
+            &Cell::Number(_) | &Cell::Empty => None,
+        })
+        .collect::<Vec<(usize, usize)>>();
+
+    let mut completed_paths = 0;
+
+    for (row_index, column_index) in starts {
+        let this_one = follow_all_0_to_9(&grid, row_index, column_index, 0);
+        completed_paths += this_one;
+    }
+
+    completed_paths.into()
+}
+
+fn follow_all_0_to_9(grid: &Grid<Cell>, row_index: usize, column_index: usize, number: u32) -> u32 {
+    let mut nexts = 0;
+
+    if number == 9 {
+        return 1;
+    }
+
+    for ((neighbor_row_index, neighbor_column_index), _) in
+        grid.hv_neighbors_where(row_index, column_index, |cell| {
+            *cell == Cell::Number(number + 1)
+        })
+    {
+        nexts += follow_all_0_to_9(grid, neighbor_row_index, neighbor_column_index, number + 1);
+    }
+
+    nexts
+}
+
+impl Parts for Solution {
+    fn part_1(&self, input: &str) -> PartSolution {
+        count_longest_paths(input)
+    }
+
+    fn part_2(&self, input: &str) -> PartSolution {
+        follow_all_paths(input)
+    }
+}
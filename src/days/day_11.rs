@@ -0,0 +1,89 @@
+use hashbrown::HashMap;
+
+use crate::shared::{PartSolution, Parts};
+
+crate::solution!(title: "Plutonian Pebbles", tags: [DynamicProgramming]);
+
+fn count_digits(mut number: u64) -> u32 {
+    if number > 0 {
+        let mut digits = 0;
+        while number != 0 {
+            number /= 10;
+
+            digits += 1;
+        }
+
+        digits
+    } else {
+        1
+    }
+}
+
+fn process_stones(input: &str, times: u32) -> PartSolution {
+    let parsed = input
+        .trim()
+        .split(' ')
+        .map(|stone| stone.parse::<u64>().expect("Bad input"))
+        .collect::<Vec<u64>>();
+
+    let mut memory1 = HashMap::<u64, u64>::new();
+    let mut memory2 = HashMap::<u64, u64>::new();
+
+    for p in parsed {
+        memory1.entry(p).and_modify(|c| *c += 1).or_insert(1);
+    }
+
+    for _blink in 0..times {
+        memory2.clear();
+
+        // if digit_memory.len() == 75 {
+        //     println!("{:?}", digit_memory);
+        //     break;
+        // }
+        // println!("{:?}", parsed);
+
+        for (value, count) in &memory1 {
+            if *value == 0 {
+                memory2
+                    .entry(1)
+                    .and_modify(|c| *c += *count)
+                    .or_insert(*count);
+            } else {
+                let digits = count_digits(*value);
+
+                if digits.is_multiple_of(2) {
+                    let left = *value / (10_u64.pow(digits / 2));
+                    let right = *value - (left * (10_u64.pow(digits / 2)));
+
+                    memory2
+                        .entry(left)
+                        .and_modify(|c| *c += *count)
+                        .or_insert(*count);
+                    memory2
+                        .entry(right)
+                        .and_modify(|c| *c += *count)
+                        .or_insert(*count);
+                } else {
+                    memory2
+                        .entry(*value * 2024)
+                        .and_modify(|c| *c += *count)
+                        .or_insert(*count);
+                }
+            }
+        }
+
+        std::mem::swap(&mut memory1, &mut memory2);
+    }
+
+    memory1.values().copied().sum::<u64>().into()
+}
+
+impl Parts for Solution {
+    fn part_1(&self, input: &str) -> PartSolution {
+        process_stones(input, 25)
+    }
+
+    fn part_2(&self, input: &str) -> PartSolution {
+        process_stones(input, 75)
+    }
+}
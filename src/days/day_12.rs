@@ -0,0 +1,125 @@
+use hashbrown::HashSet;
+
+use crate::shared::grids::grid::Grid;
+use crate::shared::grids::point::Point;
+use crate::shared::grids::{
+    GridIter as _, HorizontalVerticalDirection as Direction, Neighbors as _,
+};
+use crate::shared::{PartSolution, Parts};
+
+crate::solution!(title: "Garden Groups", tags: [Grids], uses: [grids]);
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+struct Cell(char);
+
+impl std::fmt::Debug for Cell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+fn parse_input(input: &str) -> Grid<Cell> {
+    Grid::parse(input, |c| Ok::<_, &str>(Cell(c))).expect("Bad input")
+}
+
+fn calculate_straights(input: &str) -> PartSolution {
+    let grid = parse_input(input);
+
+    let mut seen = HashSet::<(usize, usize)>::new();
+
+    let mut total = 0;
+
+    for ((row_index, column_index), _) in grid.row_column_index_value_iter() {
+        if !seen.contains(&(row_index, column_index)) {
+            let (area, _, corners) = explore_region(&grid, (row_index, column_index), &mut seen);
+
+            total += area * corners;
+        }
+    }
+
+    total.into()
+}
+
+fn calculate_fence_cost(input: &str) -> PartSolution {
+    let grid = parse_input(input);
+
+    let mut seen = HashSet::<(usize, usize)>::new();
+
+    let mut total = 0;
+
+    for ((row_index, column_index), _) in grid.row_column_index_value_iter() {
+        if !seen.contains(&(row_index, column_index)) {
+            let (area, perimeter, _) = explore_region(&grid, (row_index, column_index), &mut seen);
+
+            total += area * perimeter;
+        }
+    }
+
+    total.into()
+}
+
+fn explore_region(
+    grid: &Grid<Cell>,
+    (row_index, column_index): (usize, usize),
+    seen: &mut HashSet<(usize, usize)>,
+) -> (usize, usize, usize) {
+    let symbol = grid[row_index][column_index].0;
+    let mut to_explore_further = vec![(row_index, column_index)];
+
+    let mut area = 0;
+    let mut perimeter = 0;
+    let mut corners = 0;
+
+    while let Some((next_row_index, next_column_index)) = to_explore_further.pop() {
+        if seen.insert((next_row_index, next_column_index)) {
+            area += 1;
+
+            let mut neighbor_perimeter = 4;
+
+            for (neighbor, _) in
+                grid.hv_neighbors_where(next_row_index, next_column_index, |cell| cell.0 == symbol)
+            {
+                neighbor_perimeter -= 1;
+                to_explore_further.push(neighbor);
+            }
+
+            perimeter += neighbor_perimeter;
+
+            corners += count_corners(grid, Point::new(next_row_index, next_column_index));
+        }
+    }
+
+    // corners == sides
+    (area, perimeter, corners)
+}
+
+fn count_corners(grid: &Grid<Cell>, point: Point) -> usize {
+    let mut corners = 0;
+
+    for direction_1 in Direction::all() {
+        let direction_2 = direction_1.turn_right();
+
+        let side_1 = grid.get_offset(point, direction_1.delta());
+        let side_2 = grid.get_offset(point, direction_2.delta());
+        let corner = grid.get_offset(point, direction_1.delta() + direction_2.delta());
+
+        let cell = Some(&grid[point]);
+
+        if cell != side_1 && cell != side_2 || (cell == side_1 && cell == side_2 && cell != corner)
+        {
+            corners += 1;
+        }
+    }
+
+    corners
+}
+
+impl Parts for Solution {
+    fn part_1(&self, input: &str) -> PartSolution {
+        calculate_fence_cost(input)
+    }
+
+    fn part_2(&self, input: &str) -> PartSolution {
+        calculate_straights(input)
+    }
+}
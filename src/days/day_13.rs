@@ -0,0 +1,187 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::shared::error::SolveError;
+use crate::shared::{PartSolution, TryParts};
+
+crate::solution!(title: "Claw Contraption", tags: [Math, Parsing]);
+
+static PRIZE_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^Prize: X=(\d+), Y=(\d+)$").unwrap());
+static BUTTON_A_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^Button A: X\+(\d+), Y\+(\d+)$").unwrap());
+static BUTTON_B_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^Button B: X\+(\d+), Y\+(\d+)$").unwrap());
+
+pub struct Game {
+    button_a: (i64, i64),
+    button_b: (i64, i64),
+    prize: (i64, i64),
+}
+
+impl std::fmt::Debug for Game {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Button A: X+{}, Y+{}", self.button_a.0, self.button_a.1)?;
+        writeln!(f, "Button B: X+{}, Y+{}", self.button_b.0, self.button_b.1)?;
+        writeln!(f, "Prize: X={}, Y={}", self.prize.0, self.prize.1)?;
+
+        Ok(())
+    }
+}
+
+/// Parses the two numbers captured by `regex` from the 1-based `line_number`.
+fn parse_pair(
+    regex: &Regex,
+    expected: &str,
+    line: &str,
+    line_number: usize,
+) -> Result<(i64, i64), SolveError> {
+    let captures = regex
+        .captures(line)
+        .ok_or_else(|| SolveError::parse(line_number, 1, format!("expected `{}`", expected)))?;
+
+    let parse = |index| {
+        let capture = captures.get(index).expect("Both groups always participate");
+
+        capture.as_str().parse::<i64>().map_err(|_| {
+            SolveError::parse(
+                line_number,
+                capture.start() + 1,
+                format!("`{}` is too large", capture.as_str()),
+            )
+        })
+    };
+
+    Ok((parse(1)?, parse(2)?))
+}
+
+fn parse_input(input: &str) -> Result<Vec<Game>, SolveError> {
+    let mut games = Vec::new();
+
+    let mut button_a = None;
+    let mut button_b = None;
+
+    let mut line_count = 0;
+
+    for (line_index, line) in input.lines().enumerate() {
+        let line_number = line_index + 1;
+
+        line_count = line_number;
+
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        match (button_a, button_b) {
+            (None, _) => {
+                button_a = Some(parse_pair(&BUTTON_A_REGEX, "Button A:", line, line_number)?);
+            },
+            (Some(_), None) => {
+                button_b = Some(parse_pair(&BUTTON_B_REGEX, "Button B:", line, line_number)?);
+            },
+            (Some(a), Some(b)) => {
+                games.push(Game {
+                    button_a: a,
+                    button_b: b,
+                    prize: parse_pair(&PRIZE_REGEX, "Prize:", line, line_number)?,
+                });
+
+                button_a = None;
+                button_b = None;
+            },
+        }
+    }
+
+    match (button_a, button_b) {
+        (None, _) => Ok(games),
+        (Some(_), None) => Err(SolveError::parse(line_count + 1, 1, "expected `Button B:`")),
+        (Some(_), Some(_)) => Err(SolveError::parse(line_count + 1, 1, "expected `Prize:`")),
+    }
+}
+
+fn find_solution(game: &Game, prize_mod: i64) -> Option<i64> {
+    let a_x = game.button_a.0;
+    let a_y = game.button_a.1;
+
+    let b_x = game.button_b.0;
+    let b_y = game.button_b.1;
+
+    let prize_x = game.prize.0 + prize_mod;
+    let prize_y = game.prize.1 + prize_mod;
+
+    // cramer
+    let a_times = (prize_x * b_y - prize_y * b_x) / (a_x * b_y - a_y * b_x);
+    let b_times = (prize_y * a_x - prize_x * a_y) / (a_x * b_y - a_y * b_x);
+
+    if a_times * a_x + b_times * b_x == prize_x && a_times * a_y + b_times * b_y == prize_y {
+        Some(3 * a_times + b_times)
+    } else {
+        None
+    }
+}
+
+fn calculate_button_presses(games: &[Game], prize_mod: i64) -> PartSolution {
+    let mut total = 0;
+
+    for game in games {
+        if let Some(solution) = find_solution(game, prize_mod) {
+            total += solution;
+        }
+    }
+
+    total.into()
+}
+
+impl TryParts for Solution {
+    type Parsed<'input> = Vec<Game>;
+
+    type Params = ();
+
+    fn parse<'input>(&self, input: &'input str) -> Result<Self::Parsed<'input>, SolveError> {
+        parse_input(input)
+    }
+
+    fn try_part_1_parsed(
+        &self,
+        parsed: &Self::Parsed<'_>,
+        _params: &Self::Params,
+    ) -> Result<PartSolution, SolveError> {
+        Ok(calculate_button_presses(parsed, 0))
+    }
+
+    fn try_part_2_parsed(
+        &self,
+        parsed: &Self::Parsed<'_>,
+        _params: &Self::Params,
+    ) -> Result<PartSolution, SolveError> {
+        Ok(calculate_button_presses(parsed, 10_000_000_000_000))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    mod part_1 {
+        use crate::days::day_13::Solution;
+        use crate::shared::TryParts as _;
+        use crate::shared::error::SolveError;
+
+        #[test]
+        fn malformed() {
+            let input = "Button A: X+94, Y+34\nButton B: X+22, Y+67\nPrize: X=8400, Y=5400\n\nButton X: X+26, Y+66\n";
+
+            assert_eq!(
+                Err(SolveError::parse(5, 1, "expected `Button A:`")),
+                (Solution {}).try_part_1(input)
+            );
+        }
+
+        #[test]
+        fn truncated() {
+            assert_eq!(
+                Err(SolveError::parse(2, 1, "expected `Button B:`")),
+                (Solution {}).try_part_1("Button A: X+94, Y+34")
+            );
+        }
+    }
+}
@@ -0,0 +1,274 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::shared::error::SolveError;
+use crate::shared::{PartSolution, TryParts};
+
+crate::solution!(title: "Restroom Redoubt", tags: [Simulation], uses: [params]);
+
+crate::params! {
+    /// The width of the bathroom.
+    width: isize = 101,
+    /// The height of the bathroom.
+    height: isize = 103,
+}
+
+static ROBOT_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^p=(\d+),(\d+) v=(-?\d+),(-?\d+)$").unwrap());
+
+#[derive(Clone)]
+pub struct Robot {
+    position: (isize, isize),
+    velocity: (isize, isize),
+}
+
+impl std::fmt::Debug for Robot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "p={},{} v={},{}",
+            self.position.0, self.position.1, self.velocity.0, self.velocity.1
+        )
+    }
+}
+
+/// Parses the robot on `line`, which starts at the byte `offset` into `input`.
+fn parse_robot(input: &str, offset: usize, line: &str) -> Result<Robot, SolveError> {
+    let captures = ROBOT_REGEX
+        .captures(line)
+        .ok_or_else(|| SolveError::parse_at(input, offset, "expected `p=X,Y v=DX,DY`"))?;
+
+    let parse = |index| {
+        let capture = captures
+            .get(index)
+            .expect("Every group always participates");
+
+        capture.as_str().parse::<isize>().map_err(|_| {
+            SolveError::parse_at(
+                input,
+                offset + capture.start(),
+                format!("`{}` is too large", capture.as_str()),
+            )
+        })
+    };
+
+    Ok(Robot {
+        position: (parse(1)?, parse(2)?),
+        velocity: (parse(3)?, parse(4)?),
+    })
+}
+
+fn parse_input(input: &str) -> Result<Vec<Robot>, SolveError> {
+    let mut robots = Vec::new();
+    let mut offset = 0;
+
+    for line in input.split_inclusive('\n') {
+        let robot = line.trim_end();
+
+        if !robot.is_empty() {
+            robots.push(parse_robot(input, offset, robot)?);
+        }
+
+        offset += line.len();
+    }
+
+    Ok(robots)
+}
+
+fn calculate_safety_factor(robots: &[Robot], width: isize, height: isize) -> PartSolution {
+    let mut robots = robots.to_vec();
+
+    for _second in 0..100 {
+        for &mut Robot {
+            ref mut position,
+            velocity,
+        } in &mut robots
+        {
+            position.0 += velocity.0;
+            position.0 = position.0.rem_euclid(width);
+            position.1 += velocity.1;
+            position.1 = position.1.rem_euclid(height);
+        }
+    }
+
+    let (q1, q2, q3, q4) = count_robots_in_quadrants(&robots, width, height);
+
+    (q1 * q2 * q3 * q4).into()
+}
+
+fn find_with_lowest_variance(robots: &[Robot], width: isize, height: isize) -> PartSolution {
+    let mut robots = robots.to_vec();
+
+    let mut variances = Vec::new();
+
+    for _ in 1..=width * height + 1 {
+        for &mut Robot {
+            ref mut position,
+            velocity,
+        } in &mut robots
+        {
+            position.0 += velocity.0;
+            position.0 = position.0.rem_euclid(width);
+            position.1 += velocity.1;
+            position.1 = position.1.rem_euclid(height);
+        }
+
+        variances.push(variance(&robots));
+    }
+
+    let try_number_with_variance: Vec<_> = variances
+        .iter()
+        .enumerate()
+        .map(|(r#try, &variance)| (r#try + 1, variance))
+        .collect();
+
+    let min = try_number_with_variance
+        .iter()
+        .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+        .unwrap();
+
+    min.0.into()
+}
+
+#[expect(clippy::cast_precision_loss, reason = "Within bounds")]
+fn variance(robots: &[Robot]) -> f64 {
+    let positions: Vec<_> = robots
+        .iter()
+        .map(|robot| (robot.position.0 + robot.position.1) as f64)
+        .collect();
+
+    let mean = positions.iter().sum::<f64>() / positions.len() as f64;
+
+    let diffs: Vec<f64> = positions
+        .iter()
+        .map(|&p| {
+            let diff = p - mean;
+            diff * diff
+        })
+        .collect();
+
+    diffs.iter().sum::<f64>() / diffs.len() as f64
+}
+
+fn count_robots_in_quadrants(
+    robots: &[Robot],
+    width: isize,
+    height: isize,
+) -> (usize, usize, usize, usize) {
+    let mut q1 = 0;
+    let mut q2 = 0;
+    let mut q3 = 0;
+    let mut q4 = 0;
+
+    // ordinal, defining index at which we ignore numbers, NOT the amount per half per dimension
+    let width_separator = width / 2;
+    let height_separator = height / 2;
+
+    for &Robot { position, .. } in robots {
+        if position.0 == width_separator || position.1 == height_separator {
+            continue;
+        }
+
+        let is_left = position.0 < width_separator;
+        let is_top = position.1 < height_separator;
+
+        match (is_top, is_left) {
+            (true, false) => q1 += 1,
+            (true, true) => q2 += 1,
+            (false, true) => q3 += 1,
+            (false, false) => q4 += 1,
+        }
+    }
+
+    (q1, q2, q3, q4)
+}
+
+/// Checks the bathroom has room for the robots, as they wrap around its edges.
+fn check_size(params: &Params) -> Result<(), SolveError> {
+    if params.width <= 0 || params.height <= 0 {
+        return Err(SolveError::Invalid(format!(
+            "the bathroom must be at least 1 by 1, found {} by {}",
+            params.width, params.height
+        )));
+    }
+
+    Ok(())
+}
+
+impl TryParts for Solution {
+    type Parsed<'input> = Vec<Robot>;
+
+    type Params = Params;
+
+    fn parse<'input>(&self, input: &'input str) -> Result<Self::Parsed<'input>, SolveError> {
+        parse_input(input)
+    }
+
+    fn try_part_1_parsed(
+        &self,
+        parsed: &Self::Parsed<'_>,
+        params: &Self::Params,
+    ) -> Result<PartSolution, SolveError> {
+        check_size(params)?;
+
+        Ok(calculate_safety_factor(parsed, params.width, params.height))
+    }
+
+    fn try_part_2_parsed(
+        &self,
+        parsed: &Self::Parsed<'_>,
+        params: &Self::Params,
+    ) -> Result<PartSolution, SolveError> {
+        check_size(params)?;
+
+        Ok(find_with_lowest_variance(
+            parsed,
+            params.width,
+            params.height,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    mod part_1 {
+        use crate::days::day_14::{Params, Solution};
+        use crate::shared::TryParts as _;
+        use crate::shared::error::SolveError;
+
+        #[test]
+        fn empty_bathroom() {
+            assert_eq!(
+                Err(SolveError::Invalid(
+                    "the bathroom must be at least 1 by 1, found 0 by 7".into()
+                )),
+                (Solution {}).try_part_1_parsed(
+                    &vec![],
+                    &Params {
+                        width: 0,
+                        height: 7
+                    }
+                )
+            );
+        }
+
+        #[test]
+        fn bad_robot() {
+            assert_eq!(
+                Some(SolveError::parse(2, 1, "expected `p=X,Y v=DX,DY`")),
+                (Solution {}).parse("p=0,4 v=3,-3\np=6,3 v=-1\n").err()
+            );
+            assert_eq!(
+                Some(SolveError::parse(
+                    1,
+                    11,
+                    "`99999999999999999999` is too large"
+                )),
+                (Solution {})
+                    .parse("p=0,4 v=3,99999999999999999999\n")
+                    .err()
+            );
+        }
+    }
+}
@@ -0,0 +1,535 @@
+use part_1::solve_grid;
+use part_2::solve_doubled_grid;
+
+use crate::shared::grids::HorizontalVerticalDirection as Direction;
+use crate::shared::{PartSolution, Parts};
+
+crate::solution!(title: "Warehouse Woes", tags: [Grids, Simulation], uses: [grids]);
+
+fn parse_move(value: char) -> Result<Direction, &'static str> {
+    match value {
+        '^' => Ok(Direction::Up),
+        '>' => Ok(Direction::Right),
+        'v' => Ok(Direction::Down),
+        '<' => Ok(Direction::Left),
+        _ => Err("Invalid character"),
+    }
+}
+
+mod part_1 {
+    use crate::days::day_15::{Direction, parse_move};
+    use crate::shared::PartSolution;
+    use crate::shared::grids::GridIter as _;
+    use crate::shared::grids::grid::Grid;
+    use crate::shared::grids::point::Point;
+
+    #[derive(PartialEq, Eq, Clone, Copy)]
+    enum Cell {
+        Empty,
+        Box,
+        Wall,
+        Robot,
+    }
+
+    impl TryFrom<char> for Cell {
+        type Error = &'static str;
+
+        fn try_from(value: char) -> Result<Self, Self::Error> {
+            match value {
+                '@' => Ok(Cell::Robot),
+                '.' => Ok(Cell::Empty),
+                '#' => Ok(Cell::Wall),
+                'O' => Ok(Cell::Box),
+                _ => Err("Invalid character"),
+            }
+        }
+    }
+
+    impl std::fmt::Debug for Cell {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            let c = match *self {
+                Cell::Empty => '.',
+                Cell::Box => 'O',
+                Cell::Wall => '#',
+                Cell::Robot => '@',
+            };
+
+            write!(f, "{}", c)
+        }
+    }
+
+    fn parse_input(input: &str) -> (Grid<Cell>, Vec<Direction>) {
+        let (field, moves) = input.trim().split_once("\n\n").expect("Bad input");
+
+        let grid = field.parse().expect("Bad input");
+
+        let moves = moves
+            .lines()
+            .flat_map(str::chars)
+            .map(|c| parse_move(c).expect("Bad input"))
+            .collect();
+
+        (grid, moves)
+    }
+
+    fn move_robot(grid: &mut Grid<Cell>, moves: &[Direction]) {
+        let mut robot_position = grid
+            .row_column_index_value_iter()
+            .find(|p| matches!(*p, Cell::Robot))
+            .map(Point::from)
+            .expect("Robot gone?");
+
+        for direction in moves {
+            // we move the robot in direction.
+            // if it's a wall, we don't move.
+            // if it's empty, we move
+            // if it's a block, we go into the same direction and see if we find an empty spot until we run out of space
+            // and shift ourselves and ALL blocks to direction
+
+            let next_robot_position = grid.neighbor(robot_position, *direction);
+
+            let Some(next_robot_position) = next_robot_position else {
+                continue;
+            };
+
+            match grid[next_robot_position] {
+                Cell::Empty => {
+                    // Move robot
+                    grid[robot_position] = Cell::Empty;
+                    grid[next_robot_position] = Cell::Robot;
+
+                    robot_position = next_robot_position;
+                },
+                Cell::Box => {
+                    // in the direction we're going, see if there is an empty spot at the end
+                    let mut next = next_robot_position;
+
+                    while let Some(next_next) = grid.neighbor(next, *direction) {
+                        match grid[next_next] {
+                            Cell::Box => {
+                                next = next_next;
+                            },
+                            Cell::Empty => {
+                                // Move robot
+                                grid[robot_position] = Cell::Empty;
+                                grid[next_robot_position] = Cell::Robot;
+
+                                // Mark last Empty as Box, and now it looks like the robot pushed the whole train
+                                grid[next_next] = Cell::Box;
+
+                                robot_position = next_robot_position;
+                                break;
+                            },
+                            Cell::Wall => {
+                                // can't do anything
+                                break;
+                            },
+                            Cell::Robot => panic!("2 robots?"),
+                        }
+                    }
+                },
+                Cell::Wall => {
+                    continue;
+                },
+                Cell::Robot => panic!("Only 1 robot allowed, and we're the robot"),
+            }
+        }
+    }
+
+    fn calculate_gps_positions(grid: &Grid<Cell>) -> PartSolution {
+        let mut gps_positions = 0;
+
+        for ((row_index, column_index), cell) in grid.row_column_index_value_iter() {
+            if matches!(*cell, Cell::Box) {
+                gps_positions += 100 * row_index + column_index;
+            }
+        }
+
+        gps_positions.into()
+    }
+
+    pub(super) fn solve_grid(input: &str) -> PartSolution {
+        let (mut grid, moves) = parse_input(input);
+
+        move_robot(&mut grid, &moves);
+
+        calculate_gps_positions(&grid)
+    }
+}
+
+mod part_2 {
+    use std::cmp::Reverse;
+
+    use crate::days::day_15::{Direction, parse_move};
+    use crate::shared::PartSolution;
+    use crate::shared::grids::GridIter as _;
+    use crate::shared::grids::grid::Grid;
+    use crate::shared::grids::point::Point;
+
+    #[derive(PartialEq, Eq, Clone, Copy)]
+    enum Cell {
+        Empty,
+        BoxLeft,
+        BoxRight,
+        Wall,
+        Robot,
+    }
+
+    impl std::fmt::Display for Cell {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            let c = match *self {
+                Cell::Empty => '.',
+                Cell::BoxLeft => '[',
+                Cell::BoxRight => ']',
+                Cell::Wall => '#',
+                Cell::Robot => '@',
+            };
+
+            write!(f, "{}", c)
+        }
+    }
+
+    struct BlockMove {
+        left_piece_original: Point,
+        left_piece_new: Point,
+        right_piece_original: Point,
+        right_piece_new: Point,
+    }
+
+    fn parse_double_field_line(line: &str) -> Vec<Cell> {
+        let mut cells = Vec::with_capacity(line.len());
+
+        for (c1, c2) in line.chars().map(|c| match c {
+            '@' => (Cell::Robot, Cell::Empty),
+            '.' => (Cell::Empty, Cell::Empty),
+            '#' => (Cell::Wall, Cell::Wall),
+            'O' => (Cell::BoxLeft, Cell::BoxRight),
+            _ => panic!("Bad input"),
+        }) {
+            cells.push(c1);
+            cells.push(c2);
+        }
+
+        cells
+    }
+
+    fn parse_input_doubled(input: &str) -> (Grid<Cell>, Vec<Direction>) {
+        let mut field = Vec::<Vec<Cell>>::new();
+        let mut moves = Vec::<Direction>::new();
+
+        let mut after_linebreak = false;
+
+        for line in input.trim().lines() {
+            if line.is_empty() {
+                after_linebreak = true;
+            }
+
+            if after_linebreak {
+                for direction in line.chars().map(|c| parse_move(c).expect("Bad input")) {
+                    moves.push(direction);
+                }
+            } else {
+                field.push(parse_double_field_line(line));
+            }
+        }
+
+        let grid = Grid::new(field);
+
+        (grid, moves)
+    }
+
+    fn move_robot_doubled_grid(grid: &mut Grid<Cell>, moves: &[Direction]) {
+        let mut robot_position = grid
+            .row_column_index_value_iter()
+            .find(|p| matches!(*p, Cell::Robot))
+            .map(Point::from)
+            .expect("Robot gone?");
+
+        for direction in moves {
+            let next_robot_position = grid.neighbor(robot_position, *direction);
+
+            let Some(next_robot_position) = next_robot_position else {
+                continue;
+            };
+
+            match grid[next_robot_position] {
+                Cell::Empty => {
+                    // Move robot
+                    grid[robot_position] = Cell::Empty;
+                    grid[next_robot_position] = Cell::Robot;
+
+                    robot_position = next_robot_position;
+                },
+                Cell::BoxLeft | Cell::BoxRight => {
+                    // if direction is left / right, we basically just push again
+                    match *direction {
+                        Direction::Left | Direction::Right => {
+                            let Some(instructions) =
+                                calculate_left_right_moves(grid, next_robot_position, *direction)
+                            else {
+                                continue;
+                            };
+
+                            for BlockMove {
+                                left_piece_original,
+                                right_piece_original,
+                                left_piece_new: new_left,
+                                right_piece_new: new_right,
+                            } in instructions
+                            {
+                                grid[left_piece_original] = Cell::Empty;
+                                grid[right_piece_original] = Cell::Empty;
+
+                                grid[new_left] = Cell::BoxLeft;
+                                grid[new_right] = Cell::BoxRight;
+                            }
+
+                            grid[robot_position] = Cell::Empty;
+                            grid[next_robot_position] = Cell::Robot;
+                            robot_position = next_robot_position;
+
+                            // {
+                            //     // Move robot
+                            //     grid[robot_position] = Cell::Empty;
+                            //     grid[next_robot_position] = Cell::Robot;
+                            //     robot_position = next_robot_position;
+                            // }
+                        },
+                        Direction::Up | Direction::Down => {
+                            let Some(mut instructions) =
+                                calculate_up_down_moves(grid, next_robot_position, *direction)
+                            else {
+                                continue;
+                            };
+
+                            if *direction == Direction::Up {
+                                instructions.sort_by_key(
+                                    |&BlockMove {
+                                         left_piece_original,
+                                         ..
+                                     }| {
+                                        left_piece_original.row_index
+                                    },
+                                );
+                            } else {
+                                instructions.sort_by_key(
+                                    |&BlockMove {
+                                         left_piece_original,
+                                         ..
+                                     }| {
+                                        Reverse(left_piece_original.row_index)
+                                    },
+                                );
+                            }
+
+                            for BlockMove {
+                                left_piece_original,
+                                right_piece_original,
+                                left_piece_new,
+                                right_piece_new,
+                            } in instructions
+                            {
+                                grid[left_piece_original] = Cell::Empty;
+                                grid[right_piece_original] = Cell::Empty;
+
+                                grid[left_piece_new] = Cell::BoxLeft;
+                                grid[right_piece_new] = Cell::BoxRight;
+                            }
+
+                            grid[robot_position] = Cell::Empty;
+                            grid[next_robot_position] = Cell::Robot;
+                            robot_position = next_robot_position;
+                        },
+                    }
+                },
+                Cell::Wall => {
+                    continue;
+                },
+                Cell::Robot => panic!("Only 1 robot allowed, and we're the robot"),
+            }
+        }
+    }
+
+    fn calculate_gps_positions_doubled_grid(grid: &Grid<Cell>) -> PartSolution {
+        let mut gps_positions = 0;
+
+        for ((row_index, column_index), cell) in grid.row_column_index_value_iter() {
+            if matches!(*cell, Cell::BoxLeft) {
+                gps_positions += 100 * row_index + column_index;
+            }
+        }
+
+        gps_positions.into()
+    }
+
+    fn calculate_up_down_moves(
+        grid: &Grid<Cell>,
+        position: Point,
+        direction: Direction,
+    ) -> Option<Vec<BlockMove>> {
+        // expand block
+        let (original_left, original_right) = if matches!(grid[position], Cell::BoxLeft) {
+            (
+                position,
+                Point::new(position.row_index, position.column_index + 1),
+            )
+        } else {
+            (
+                Point::new(position.row_index, position.column_index - 1),
+                position,
+            )
+        };
+
+        // try and move the pieces up or down
+        // if the next position is wall or robot (!), fail
+        // if the next position is empty, save (TODO!)
+        // if the next position is a piece, kk
+
+        // can we move both pieces in the direction?
+        let (Some(left_moved), Some(right_moved)) = (
+            grid.neighbor(original_left, direction),
+            grid.neighbor(original_right, direction),
+        ) else {
+            return None;
+        };
+
+        match (grid[left_moved], grid[right_moved]) {
+            (Cell::BoxRight, Cell::Empty) | (Cell::BoxLeft, Cell::BoxRight) => {
+                let mut left_descendant_moves =
+                    calculate_up_down_moves(grid, left_moved, direction)?;
+
+                left_descendant_moves.push(BlockMove {
+                    left_piece_original: original_left,
+                    right_piece_original: original_right,
+
+                    left_piece_new: left_moved,
+                    right_piece_new: right_moved,
+                });
+
+                Some(left_descendant_moves)
+            },
+            (Cell::Empty, Cell::BoxLeft) => {
+                let mut right_descendants_moves =
+                    calculate_up_down_moves(grid, right_moved, direction)?;
+
+                right_descendants_moves.push(BlockMove {
+                    left_piece_original: original_left,
+                    right_piece_original: original_right,
+
+                    left_piece_new: left_moved,
+                    right_piece_new: right_moved,
+                });
+
+                Some(right_descendants_moves)
+            },
+            (Cell::BoxRight, Cell::BoxLeft) => {
+                let mut ll = calculate_up_down_moves(grid, left_moved, direction)?;
+
+                let mut rr = calculate_up_down_moves(grid, right_moved, direction)?;
+
+                ll.append(&mut rr);
+
+                ll.push(BlockMove {
+                    left_piece_original: original_left,
+                    right_piece_original: original_right,
+                    left_piece_new: left_moved,
+                    right_piece_new: right_moved,
+                });
+
+                Some(ll)
+            },
+            (Cell::Empty, Cell::Empty) => Some(vec![BlockMove {
+                left_piece_original: original_left,
+                right_piece_original: original_right,
+                left_piece_new: left_moved,
+                right_piece_new: right_moved,
+            }]),
+            _ => None,
+        }
+    }
+
+    fn calculate_left_right_moves(
+        grid: &mut Grid<Cell>,
+        position: Point,
+        direction: Direction,
+    ) -> Option<Vec<BlockMove>> {
+        // in the direction we're going, see if there is an empty spot at the end
+        let mut block_piece_position = position;
+
+        while let Some(next_block_piece_position) = grid.step(block_piece_position, direction, 2) {
+            match grid[next_block_piece_position] {
+                Cell::Empty => {
+                    if direction == Direction::Right {
+                        let mut instructions = vec![];
+
+                        // reverse, as we want to move the blocks from right to left to prevent overwriting previous one
+                        for column in (position.column_index
+                            ..next_block_piece_position.column_index)
+                            .step_by(2)
+                            .rev()
+                        {
+                            instructions.push(BlockMove {
+                                left_piece_original: Point::new(position.row_index, column),
+                                left_piece_new: Point::new(position.row_index, column + 1),
+                                right_piece_original: Point::new(position.row_index, column + 1),
+                                right_piece_new: Point::new(position.row_index, column + 2),
+                            });
+                        }
+
+                        return Some(instructions);
+                    } else if direction == Direction::Left {
+                        let mut instructions = vec![];
+
+                        for column in (next_block_piece_position.column_index + 1
+                            ..position.column_index)
+                            .step_by(2)
+                        {
+                            instructions.push(BlockMove {
+                                left_piece_original: Point::new(position.row_index, column),
+                                left_piece_new: Point::new(position.row_index, column - 1),
+                                right_piece_original: Point::new(position.row_index, column + 1),
+                                right_piece_new: Point::new(position.row_index, column),
+                            });
+                        }
+
+                        return Some(instructions);
+                    } else {
+                        // we only care for left & right
+                    }
+
+                    panic!()
+
+                    // return Some(position);
+                },
+                Cell::BoxLeft | Cell::BoxRight => {
+                    block_piece_position = next_block_piece_position;
+                },
+                Cell::Wall => {
+                    // can't do anything
+                    return None;
+                },
+                Cell::Robot => panic!("2 robots?"),
+            }
+        }
+
+        None
+    }
+
+    pub(super) fn solve_doubled_grid(input: &str) -> PartSolution {
+        let (mut grid, directions) = parse_input_doubled(input);
+
+        move_robot_doubled_grid(&mut grid, &directions);
+
+        calculate_gps_positions_doubled_grid(&grid)
+    }
+}
+
+impl Parts for Solution {
+    fn part_1(&self, input: &str) -> PartSolution {
+        solve_grid(input)
+    }
+
+    fn part_2(&self, input: &str) -> PartSolution {
+        solve_doubled_grid(input)
+    }
+}
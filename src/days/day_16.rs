@@ -0,0 +1,256 @@
+use std::collections::BinaryHeap;
+use std::hash::Hash;
+
+use hashbrown::{HashMap, HashSet};
+
+use crate::shared::error::SolveError;
+use crate::shared::grids::HorizontalVerticalDirection as Direction;
+use crate::shared::grids::grid::Grid;
+use crate::shared::grids::point::Point;
+use crate::shared::{PartSolution, TryParts};
+
+crate::solution!(title: "Reindeer Maze", tags: [Grids, Pathfinding], uses: [grids]);
+
+enum Cell {
+    Start,
+    End,
+    Wall,
+    Empty,
+}
+
+impl std::fmt::Display for Cell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let c = match *self {
+            Cell::Start => 'S',
+            Cell::End => 'E',
+            Cell::Wall => '#',
+            Cell::Empty => '.',
+        };
+
+        write!(f, "{}", c)
+    }
+}
+
+impl TryFrom<char> for Cell {
+    type Error = &'static str;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'S' => Ok(Cell::Start),
+            'E' => Ok(Cell::End),
+            '#' => Ok(Cell::Wall),
+            '.' => Ok(Cell::Empty),
+            _ => Err("expected one of `S`, `E`, `#` or `.`"),
+        }
+    }
+}
+
+pub struct Maze {
+    grid: Grid<Cell>,
+    start: Point,
+    end: Point,
+}
+
+fn parse_input(input: &str) -> Result<Maze, SolveError> {
+    let (grid, [start, end]) = Grid::parse_marked(input, ['S', 'E'], Cell::try_from)?;
+
+    Ok(Maze { grid, start, end })
+}
+
+fn get_neighbor_directions(grid: &Grid<Cell>, current: &At) -> Vec<(Point, Direction)> {
+    let mut neighbors = Vec::with_capacity(3);
+
+    for direction in [
+        current.direction,
+        current.direction.turn_left(),
+        current.direction.turn_right(),
+    ] {
+        if let Some(neighbor) = grid.neighbor(current.coordinates, direction)
+            && matches!(grid[neighbor], Cell::Empty | Cell::End)
+        {
+            neighbors.push((neighbor, direction));
+        }
+    }
+
+    neighbors
+}
+
+#[derive(Eq, PartialEq, Copy, Clone, Hash)]
+struct At {
+    coordinates: Point,
+    direction: Direction,
+}
+
+#[derive(Eq, PartialEq, Clone)]
+struct Journey {
+    current: At,
+    history: Vec<Point>,
+}
+
+#[derive(Eq, PartialEq)]
+struct JourneyWithCost {
+    journey: Journey,
+    cost: u64,
+}
+
+impl std::cmp::Ord for JourneyWithCost {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.cost.cmp(&other.cost).reverse()
+    }
+}
+
+impl std::cmp::PartialOrd for JourneyWithCost {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn calculate_cost(maze: &Maze) -> PartSolution {
+    let Maze {
+        ref grid,
+        start,
+        end,
+    } = *maze;
+
+    let mut heap = BinaryHeap::new();
+
+    heap.push(JourneyWithCost {
+        journey: Journey {
+            current: At {
+                coordinates: start,
+                direction: Direction::Right,
+            },
+            history: vec![],
+        },
+        cost: 0,
+    });
+
+    let mut seen = HashMap::<At, u64>::new();
+
+    while let Some(JourneyWithCost { journey, cost }) = heap.pop() {
+        if journey.current.coordinates == end {
+            return cost.into();
+        } else if seen.get(&journey.current).copied().unwrap_or(u64::MAX) > cost {
+            seen.insert(journey.current, cost);
+
+            for (neighbor_coordinates, neighbor_direction) in
+                get_neighbor_directions(grid, &journey.current)
+            {
+                let mut clone = journey.clone();
+                clone.history.push(clone.current.coordinates);
+                clone.current.coordinates = neighbor_coordinates;
+                clone.current.direction = neighbor_direction;
+
+                if neighbor_direction == journey.current.direction {
+                    heap.push(JourneyWithCost {
+                        journey: clone,
+                        cost: cost + 1,
+                    });
+                } else {
+                    heap.push(JourneyWithCost {
+                        journey: clone,
+                        cost: cost + 1001,
+                    });
+                }
+            }
+        } else {
+            // ...
+        }
+    }
+
+    panic!("No solution found")
+}
+
+fn count_all_points_on_lowest_cost_paths(maze: &Maze) -> PartSolution {
+    let Maze {
+        ref grid,
+        start,
+        end,
+    } = *maze;
+
+    let mut heap = BinaryHeap::new();
+
+    heap.push(JourneyWithCost {
+        journey: Journey {
+            current: At {
+                coordinates: start,
+                direction: Direction::Right,
+            },
+            history: vec![],
+        },
+        cost: 0,
+    });
+
+    let mut seen = HashMap::<At, u64>::new();
+
+    let mut cheapest_path = None;
+
+    let mut all_visited_spots = HashSet::new();
+
+    while let Some(JourneyWithCost { journey, cost }) = heap.pop() {
+        if cheapest_path.is_some_and(|cost_at_goal| cost > cost_at_goal) {
+            return all_visited_spots.len().into();
+        } else if journey.current.coordinates == end {
+            cheapest_path = Some(cost);
+
+            all_visited_spots.insert(journey.current.coordinates);
+
+            for coordinates in journey.history {
+                all_visited_spots.insert(coordinates);
+            }
+        } else if seen.get(&journey.current).copied().unwrap_or(u64::MAX) >= cost {
+            seen.insert(journey.current, cost);
+
+            for (neighbor_coordinates, neighbor_direction) in
+                get_neighbor_directions(grid, &journey.current)
+            {
+                let mut clone = journey.clone();
+                clone.history.push(journey.current.coordinates);
+                clone.current.coordinates = neighbor_coordinates;
+                clone.current.direction = neighbor_direction;
+
+                if neighbor_direction == journey.current.direction {
+                    heap.push(JourneyWithCost {
+                        journey: clone,
+                        cost: cost + 1,
+                    });
+                } else {
+                    heap.push(JourneyWithCost {
+                        journey: clone,
+                        cost: cost + 1001,
+                    });
+                }
+            }
+        } else {
+            // ...
+        }
+    }
+
+    all_visited_spots.len().into()
+}
+
+impl TryParts for Solution {
+    type Parsed<'input> = Maze;
+
+    type Params = ();
+
+    fn parse<'input>(&self, input: &'input str) -> Result<Self::Parsed<'input>, SolveError> {
+        parse_input(input)
+    }
+
+    fn try_part_1_parsed(
+        &self,
+        parsed: &Self::Parsed<'_>,
+        _params: &Self::Params,
+    ) -> Result<PartSolution, SolveError> {
+        Ok(calculate_cost(parsed))
+    }
+
+    fn try_part_2_parsed(
+        &self,
+        parsed: &Self::Parsed<'_>,
+        _params: &Self::Params,
+    ) -> Result<PartSolution, SolveError> {
+        Ok(count_all_points_on_lowest_cost_paths(parsed))
+    }
+}
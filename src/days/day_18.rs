@@ -0,0 +1,292 @@
+use std::collections::BinaryHeap;
+
+use hashbrown::HashMap;
+
+use crate::shared::error::SolveError;
+use crate::shared::grids::Neighbors as _;
+use crate::shared::grids::grid::Grid;
+use crate::shared::grids::point::Point;
+use crate::shared::{PartSolution, TryParts};
+
+crate::solution!(title: "RAM Run", tags: [Grids, Pathfinding], uses: [grids, params]);
+
+crate::params! {
+    /// The width and height of the memory space.
+    size: usize = 71,
+    /// How many bytes have fallen in part 1.
+    bytes: usize = 1024,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Empty,
+    Corrupted,
+}
+
+impl std::fmt::Display for Cell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let c = match *self {
+            Cell::Corrupted => '#',
+            Cell::Empty => '.',
+        };
+
+        write!(f, "{}", c)
+    }
+}
+
+#[derive(Eq, Copy, Clone)]
+struct Node {
+    coordinates: Point,
+    cost: u32,
+}
+
+impl std::cmp::PartialEq for Node {
+    fn eq(&self, other: &Self) -> bool {
+        self.coordinates == other.coordinates
+    }
+}
+
+impl std::hash::Hash for Node {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.coordinates.hash(state);
+    }
+}
+
+impl std::cmp::Ord for Node {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.cost.cmp(&other.cost).reverse()
+    }
+}
+
+impl std::cmp::PartialOrd for Node {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn distance(_map: &Grid<Cell>, _c: Point, _n: Point) -> u32 {
+    1
+}
+
+#[expect(clippy::cast_possible_truncation, reason = "We remain within bounds")]
+fn heuristic(_map: &Grid<Cell>, current: Point, goal: Point) -> u32 {
+    current.manhattan(goal) as u32
+}
+
+fn reconstruct_path<'l1, 'l2>(
+    came_from: &'l1 HashMap<Node, Node>,
+    mut current: &'l2 Node,
+) -> Vec<Point>
+where
+    'l1: 'l2,
+{
+    let mut total_path = vec![current.coordinates];
+
+    while let Some(next) = came_from.get(current) {
+        total_path.push(next.coordinates);
+
+        current = next;
+    }
+
+    total_path.reverse();
+
+    total_path
+}
+
+fn a_star(map: &Grid<Cell>, start: Point, goal: Point) -> Option<(HashMap<Node, Node>, Node)> {
+    let start = Node {
+        coordinates: start,
+        cost: heuristic(map, start, goal),
+    };
+
+    let mut came_from = HashMap::<Node, Node>::new();
+
+    let mut g_score: HashMap<Node, u32> = HashMap::from([(start, 0)]);
+
+    // we don't keep an f_score as it is incoorporated in the Node
+    let mut open_set = BinaryHeap::from([start]);
+
+    while let Some(node) = open_set.pop() {
+        if node.coordinates == goal {
+            return Some((came_from, node));
+        }
+
+        let (row_index, column_index) = node.coordinates.into();
+
+        for (neighbor, _) in
+            map.hv_neighbors_where(row_index, column_index, |cell| *cell == Cell::Empty)
+        {
+            let neighbor = Point::from(neighbor);
+
+            let tentative_g_score =
+                g_score.get(&node).unwrap() + distance(map, node.coordinates, neighbor);
+
+            let f_score = tentative_g_score + heuristic(map, neighbor, goal);
+
+            let neighbor_node = Node {
+                coordinates: neighbor,
+                cost: f_score,
+            };
+
+            if tentative_g_score < *g_score.get(&neighbor_node).unwrap_or(&u32::MAX) {
+                g_score.insert(neighbor_node, tentative_g_score);
+                came_from.insert(neighbor_node, node);
+                // we _could_ check if the open_set already contains the neighbor node, but
+                // this is so costly that it's easier to insert with a higher cost and then it'll get either ignored
+                // or discarded when the score is considered too high
+                open_set.push(neighbor_node);
+            }
+        }
+    }
+
+    None
+}
+
+fn parse_input(input: &str) -> Result<Vec<(usize, usize)>, SolveError> {
+    input
+        .trim()
+        .lines()
+        .enumerate()
+        .map(|(line_index, line)| {
+            let (left, right) = line
+                .split_once(',')
+                .ok_or_else(|| SolveError::parse(line_index + 1, 1, "expected `X,Y`"))?;
+
+            let parse = |value: &str, column| {
+                value.parse::<usize>().map_err(|_| {
+                    SolveError::parse(
+                        line_index + 1,
+                        column,
+                        format!("expected a coordinate, found `{}`", value),
+                    )
+                })
+            };
+
+            Ok((parse(left, 1)?, parse(right, left.len() + 2)?))
+        })
+        .collect()
+}
+
+/// The empty `size` by `size` memory space, after checking every byte falls inside it.
+fn empty_memory(falling_bytes: &[(usize, usize)], size: usize) -> Result<Grid<Cell>, SolveError> {
+    if size == 0 {
+        return Err(SolveError::Invalid(
+            "the memory space must have a size of at least 1".into(),
+        ));
+    }
+
+    for (line_index, &(x, y)) in falling_bytes.iter().enumerate() {
+        if x >= size || y >= size {
+            return Err(SolveError::parse(
+                line_index + 1,
+                1,
+                format!(
+                    "expected a byte within a memory space of size {}, found `{},{}`",
+                    size, x, y
+                ),
+            ));
+        }
+    }
+
+    Ok(Grid::new(vec![vec![Cell::Empty; size]; size]))
+}
+
+fn fall_bytes(
+    falling_bytes: &[(usize, usize)],
+    size: usize,
+    take: usize,
+) -> Result<PartSolution, SolveError> {
+    let mut grid = empty_memory(falling_bytes, size)?;
+
+    for &(x, y) in falling_bytes.iter().take(take) {
+        grid[y][x] = Cell::Corrupted;
+    }
+
+    let steps = match a_star(&grid, (0, 0).into(), (size - 1, size - 1).into()) {
+        Some((came_from, node)) => (reconstruct_path(&came_from, &node).len() - 1).into(),
+        None => PartSolution::None,
+    };
+
+    Ok(steps)
+}
+
+fn fall_bytes_until(
+    falling_bytes: &[(usize, usize)],
+    size: usize,
+) -> Result<PartSolution, SolveError> {
+    let mut grid = empty_memory(falling_bytes, size)?;
+
+    for &(x, y) in falling_bytes {
+        grid[y][x] = Cell::Corrupted;
+
+        if a_star(&grid, (0, 0).into(), (size - 1, size - 1).into()).is_none() {
+            return Ok(PartSolution::Coordinates(
+                i64::try_from(x).expect("Coordinates fit in an i64"),
+                i64::try_from(y).expect("Coordinates fit in an i64"),
+            ));
+        }
+    }
+
+    Ok(PartSolution::None)
+}
+
+impl TryParts for Solution {
+    type Parsed<'input> = Vec<(usize, usize)>;
+
+    type Params = Params;
+
+    fn parse<'input>(&self, input: &'input str) -> Result<Self::Parsed<'input>, SolveError> {
+        parse_input(input)
+    }
+
+    fn try_part_1_parsed(
+        &self,
+        parsed: &Self::Parsed<'_>,
+        params: &Self::Params,
+    ) -> Result<PartSolution, SolveError> {
+        fall_bytes(parsed, params.size, params.bytes)
+    }
+
+    fn try_part_2_parsed(
+        &self,
+        parsed: &Self::Parsed<'_>,
+        params: &Self::Params,
+    ) -> Result<PartSolution, SolveError> {
+        fall_bytes_until(parsed, params.size)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    mod part_1 {
+        use crate::days::day_18::{Params, Solution};
+        use crate::shared::TryParts as _;
+        use crate::shared::error::SolveError;
+
+        #[test]
+        fn malformed() {
+            assert_eq!(
+                Err(SolveError::parse(2, 3, "expected a coordinate, found `x`")),
+                (Solution {}).try_part_1("5,4\n4,x\n")
+            );
+        }
+
+        #[test]
+        fn outside_memory_space() {
+            let bytes = vec![(5, 4), (4, 7)];
+
+            assert_eq!(
+                Err(SolveError::parse(
+                    2,
+                    1,
+                    "expected a byte within a memory space of size 7, found `4,7`"
+                )),
+                (Solution {}).try_part_1_parsed(&bytes, &Params { size: 7, bytes: 2 })
+            );
+            assert!(matches!(
+                (Solution {}).try_part_2_parsed(&bytes, &Params { size: 0, bytes: 2 }),
+                Err(SolveError::Invalid(_))
+            ));
+        }
+    }
+}
@@ -0,0 +1,157 @@
+use crate::shared::error::SolveError;
+use crate::shared::grids::grid::Grid;
+use crate::shared::grids::point::Point;
+use crate::shared::grids::{GridIter as _, HorizontalVerticalDirection as Direction};
+use crate::shared::{PartSolution, TryParts};
+
+crate::solution!(title: "Race Condition", tags: [Grids, Pathfinding], uses: [grids, params]);
+
+crate::params! {
+    /// How many picoseconds a cheat has to save at least to be counted.
+    min_saved: usize = 100,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Empty,
+    Wall,
+    Start,
+    End,
+}
+
+impl std::fmt::Display for Cell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let c = match *self {
+            Cell::Empty => '.',
+            Cell::Wall => '#',
+            Cell::Start => 'S',
+            Cell::End => 'E',
+        };
+
+        write!(f, "{}", c)
+    }
+}
+
+impl TryFrom<char> for Cell {
+    type Error = &'static str;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '#' => Ok(Cell::Wall),
+            '.' => Ok(Cell::Empty),
+            'S' => Ok(Cell::Start),
+            'E' => Ok(Cell::End),
+            _ => Err("expected one of `#`, `.`, `S` or `E`"),
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Point>, SolveError> {
+    let (grid, [start, end]) = Grid::parse_marked(input, ['S', 'E'], Cell::try_from)?;
+
+    let mut coordinates = vec![start];
+
+    let mut current = start;
+    let mut last = current;
+
+    while current != end {
+        let new = Direction::all()
+            .into_iter()
+            .filter_map(|direction| grid.neighbor(current, direction))
+            .filter(|&neighbor| !matches!(grid[neighbor], Cell::Wall))
+            .find(|&neighbor| neighbor != last)
+            .ok_or_else(|| {
+                SolveError::parse(
+                    current.row_index + 1,
+                    current.column_index + 1,
+                    "expected the track to continue to `E`, found a dead end",
+                )
+            })?;
+
+        coordinates.push(new);
+
+        if coordinates.len() > grid.get_row_length() * grid.get_column_length() {
+            return Err(SolveError::Invalid(
+                "the track loops without reaching `E`".into(),
+            ));
+        }
+
+        last = current;
+        current = new;
+    }
+
+    Ok(coordinates)
+}
+
+fn find_cheats(coordinates: &[Point], goal: usize, max_cheat_time: usize) -> usize {
+    let mut cheat = 0;
+
+    for start in 0..coordinates.len() {
+        for end in start + goal..coordinates.len() {
+            let distance = coordinates[start].manhattan(coordinates[end]);
+
+            if distance <= max_cheat_time && distance <= end - start - goal {
+                cheat += 1;
+            }
+        }
+    }
+
+    cheat
+}
+
+impl TryParts for Solution {
+    type Parsed<'input> = Vec<Point>;
+
+    type Params = Params;
+
+    fn parse<'input>(&self, input: &'input str) -> Result<Self::Parsed<'input>, SolveError> {
+        parse_input(input)
+    }
+
+    fn try_part_1_parsed(
+        &self,
+        parsed: &Self::Parsed<'_>,
+        params: &Self::Params,
+    ) -> Result<PartSolution, SolveError> {
+        Ok(find_cheats(parsed, params.min_saved, 2).into())
+    }
+
+    fn try_part_2_parsed(
+        &self,
+        parsed: &Self::Parsed<'_>,
+        params: &Self::Params,
+    ) -> Result<PartSolution, SolveError> {
+        Ok(find_cheats(parsed, params.min_saved, 20).into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    mod part_1 {
+        use crate::days::day_20::Solution;
+        use crate::shared::TryParts as _;
+        use crate::shared::error::SolveError;
+
+        #[test]
+        fn dead_end() {
+            assert_eq!(
+                Err(SolveError::parse(
+                    1,
+                    2,
+                    "expected the track to continue to `E`, found a dead end"
+                )),
+                (Solution {}).try_part_1("S.#E\n")
+            );
+        }
+
+        #[test]
+        fn loop_without_end() {
+            assert_eq!(
+                Err(SolveError::Invalid(
+                    "the track loops without reaching `E`".into()
+                )),
+                (Solution {}).try_part_1("S..\n.#.\n...\n###\n##E\n")
+            );
+        }
+    }
+}
@@ -1,2 +1,3 @@
 pub mod days;
+pub mod runner;
 pub mod shared;
@@ -0,0 +1,980 @@
+pub mod args;
+pub mod baseline;
+pub mod bench;
+pub mod scaffold;
+pub mod solver;
+
+use std::env;
+#[cfg(feature = "serde")]
+use std::fs::File;
+#[cfg(feature = "serde")]
+use std::io::BufWriter;
+use std::io::{self, IsTerminal as _, Write as _};
+use std::path::Path;
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use args::Mode;
+use baseline::{BaselineOptions, Compare};
+use bench::{Benchmark, Options, Stats};
+use scaffold::NewDay;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use solver::Solver;
+
+use crate::days;
+use crate::shared::PartSolution;
+use crate::shared::answers::Answers;
+use crate::shared::day::Day;
+use crate::shared::day_set::DaySet;
+use crate::shared::error::SolveError;
+use crate::shared::metadata::Status;
+use crate::shared::params::Param;
+use crate::shared::part::Part;
+use crate::shared::puzzle::PuzzleId;
+use crate::shared::solution::{Input, data_dirs, read_input, try_read_file};
+use crate::shared::year::Year;
+
+/// The answer to a single part, and how long it took.
+pub struct Answer {
+    pub part: Part,
+    pub answer: Result<PartSolution, SolveError>,
+    pub elapsed: Duration,
+}
+
+/// How the answer to a part compared to the known answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Outcome {
+    /// The answer matches the known answer.
+    Passed,
+    /// The answer differs from the known answer.
+    Failed,
+    /// There was nothing to compare the answer to, or it wasn't asked for.
+    Unchecked,
+    /// The part could not be solved.
+    Error,
+}
+
+/// The result of solving a part while running, as written by `--json`, one per line.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RunResult {
+    pub puzzle: PuzzleId,
+    pub part: Part,
+    /// `None` when the part could not be solved.
+    pub answer: Option<PartSolution>,
+    pub elapsed: Duration,
+    pub outcome: Outcome,
+}
+
+impl RunResult {
+    fn error(puzzle: PuzzleId, part: Part, elapsed: Duration) -> Self {
+        RunResult {
+            puzzle,
+            part,
+            answer: None,
+            elapsed,
+            outcome: Outcome::Error,
+        }
+    }
+}
+
+/// Parses `input` once and solves the requested `parts` with it, with the default parameters
+/// overridden by `params`.
+///
+/// Returns how long parsing took, and the answers in the order of `parts`.
+///
+/// # Errors
+/// When a parameter cannot be overridden or the input is malformed
+pub fn solve(
+    solution: &dyn Solver,
+    input: &str,
+    params: &[Param],
+    parts: &[Part],
+) -> Result<(Duration, Vec<Answer>), SolveError> {
+    let mut answers = Vec::with_capacity(parts.len());
+
+    let parse_elapsed = solution.parse_then(input, params, &mut |parsed| {
+        for &part in parts {
+            let start = Instant::now();
+
+            let answer = parsed.solve(part);
+
+            answers.push(Answer {
+                part,
+                answer,
+                elapsed: start.elapsed(),
+            });
+        }
+    })?;
+
+    Ok((parse_elapsed, answers))
+}
+
+/// What went wrong, or was recorded, while solving, and the result of every part.
+#[derive(Default)]
+struct Tally {
+    mismatches: usize,
+    errors: usize,
+    recorded: usize,
+    results: Vec<RunResult>,
+}
+
+/// The puzzles of `year` whose day is in `days`, in order.
+fn puzzles(year: Year, days: &DaySet) -> impl Iterator<Item = PuzzleId> {
+    let puzzle = move |day| PuzzleId::try_new(year, day);
+
+    days.iter(year, move |day| {
+        puzzle(day)
+            .and_then(days::metadata)
+            .map_or(&[], |metadata| metadata.tags)
+    })
+    .filter_map(puzzle)
+}
+
+/// Solves the requested parts of every day in `days` of `year` with `params` and prints the
+/// answers. Unless `mode` is [`Mode::Solve`] or there are `params`, the answers are checked against
+/// the known answers. With a `json` file, the [`RunResult`] of every part is written to it as JSON
+/// lines.
+#[must_use]
+pub fn run(
+    year: Year,
+    days: &DaySet,
+    parts: &[Part],
+    params: &[Param],
+    mode: Mode,
+    json: Option<&Path>,
+) -> ExitCode {
+    let answers_path = Answers::<Day>::path(year);
+
+    let mut answers = match Answers::load(&answers_path) {
+        Ok(answers) => answers,
+        Err(error) => {
+            eprintln!("error: {}", error);
+
+            return ExitCode::FAILURE;
+        },
+    };
+
+    let mut tally = Tally::default();
+
+    for puzzle in puzzles(year, days) {
+        let Some(solution) = days::get(puzzle) else {
+            println!("Day {}: no solution registered", puzzle);
+            continue;
+        };
+
+        let input = match try_read_file("inputs", &puzzle) {
+            Ok(input) => input,
+            Err(error) => {
+                eprintln!("Day {}: {}", puzzle, error);
+
+                tally.errors += 1;
+                tally.results.extend(
+                    parts
+                        .iter()
+                        .map(|&part| RunResult::error(puzzle, part, Duration::ZERO)),
+                );
+                continue;
+            },
+        };
+
+        solve_day(
+            puzzle,
+            solution,
+            &input,
+            parts,
+            params,
+            solve_mode(mode, params, &Input::Data),
+            &mut answers,
+            &mut tally,
+        );
+    }
+
+    finish(&tally, &answers, &answers_path, json)
+}
+
+/// The timings of every part that was benchmarked, and how many days could not be.
+#[derive(Debug, Default)]
+pub struct BenchTally {
+    pub benchmarks: Vec<Benchmark>,
+    pub errors: usize,
+}
+
+/// Benchmarks the requested parts of every day in `days` of `year`, printing the timings of each
+/// day as it goes and an overview of all of them, slowest first, at the end.
+#[must_use]
+pub fn bench(
+    year: Year,
+    days: &DaySet,
+    parts: &[Part],
+    params: &[Param],
+    options: Options,
+) -> BenchTally {
+    let mut tally = BenchTally::default();
+
+    for puzzle in puzzles(year, days) {
+        let Some(solution) = days::get(puzzle) else {
+            println!("Day {}: no solution registered", puzzle);
+            continue;
+        };
+
+        let input = match try_read_file("inputs", &puzzle) {
+            Ok(input) => input,
+            Err(error) => {
+                eprintln!("Day {}: {}", puzzle, error);
+
+                tally.errors += 1;
+                continue;
+            },
+        };
+
+        println!("Day {}", puzzle);
+
+        match bench::bench(puzzle, solution, &input, parts, params, options) {
+            Ok(Some(day_benchmark)) => {
+                println!("{}", stats_header(""));
+                println!("{}", stats_row("Parse", &day_benchmark.parse));
+
+                for benchmark in &day_benchmark.parts {
+                    println!(
+                        "{}",
+                        stats_row(&format!("Part {}", benchmark.part), &benchmark.stats)
+                    );
+                }
+
+                tally.benchmarks.extend(day_benchmark.parts);
+            },
+            Ok(None) => println!("Day {}: nothing was measured", puzzle),
+            Err((part, error)) => {
+                print_error(puzzle, part, &error);
+
+                tally.errors += 1;
+            },
+        }
+    }
+
+    if !tally.benchmarks.is_empty() {
+        print_overview(&tally.benchmarks);
+    }
+
+    if tally.errors > 0 {
+        eprintln!("{} day(s) could not be benchmarked", tally.errors);
+    }
+
+    tally
+}
+
+/// Prints a table of every day of `year`'s event, with the title, status, complexity and tags of
+/// its solution, or a status of `none` when there is no solution. Solutions that don't declare
+/// their status are as far along as the answers known for them.
+#[must_use]
+pub fn list(year: Year) -> ExitCode {
+    let answers = match Answers::<Day>::load(&Answers::<Day>::path(year)) {
+        Ok(answers) => answers,
+        Err(error) => {
+            eprintln!("error: {}", error);
+
+            return ExitCode::FAILURE;
+        },
+    };
+
+    println!(
+        "{:<5}{:<26}{:<10}{:<14}Tags",
+        "Day", "Title", "Status", "Complexity"
+    );
+
+    for puzzle in puzzles(year, &DaySet::all()) {
+        let Some(metadata) = days::metadata(puzzle) else {
+            println!("{:<5}{:<26}none", puzzle.day().to_string(), "");
+            continue;
+        };
+
+        let tags = metadata
+            .tags
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+
+        let status = metadata.status.unwrap_or_else(|| {
+            Status::from_known(
+                Part::ALL
+                    .into_iter()
+                    .filter(|&part| answers.get(puzzle.day(), part).is_some())
+                    .count(),
+            )
+        });
+
+        let row = format!(
+            "{:<5}{:<26}{:<10}{:<14}{}",
+            puzzle.day().to_string(),
+            metadata.title.unwrap_or_default(),
+            status,
+            metadata.complexity.unwrap_or_default(),
+            tags.join(", ")
+        );
+
+        println!("{}", row.trim_end());
+    }
+
+    ExitCode::SUCCESS
+}
+
+/// Creates the files of a new day in this crate, and its data in the first data directory that
+/// exists, see [`scaffold::scaffold`].
+#[must_use]
+pub fn new(new_day: &NewDay) -> ExitCode {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let data_dir = data_dirs()
+        .into_iter()
+        .find(|data_dir| data_dir.exists())
+        .unwrap_or_else(|| root.join("data"));
+
+    match scaffold::scaffold(root, &data_dir, new_day) {
+        Ok(paths) => {
+            for path in paths {
+                println!("{}", path.display());
+            }
+
+            ExitCode::SUCCESS
+        },
+        Err(error) => {
+            eprintln!("error: {}", error);
+
+            ExitCode::FAILURE
+        },
+    }
+}
+
+/// Compares `benchmarks` to a saved baseline and saves them as a new one, as far as `options`
+/// asks for either.
+///
+/// Fails when a part got slower than the threshold, or the baseline file cannot be used.
+#[must_use]
+pub fn compare_and_save(benchmarks: &[Benchmark], options: &BaselineOptions) -> ExitCode {
+    if options.compare.is_none() && !options.save {
+        return ExitCode::SUCCESS;
+    }
+
+    let mut records = match baseline::load(&options.file) {
+        Ok(records) => records,
+        Err(error) => {
+            eprintln!("error: {}", error);
+
+            return ExitCode::FAILURE;
+        },
+    };
+
+    let mut exit_code = ExitCode::SUCCESS;
+
+    match options.compare {
+        Some(Compare::Latest) => match baseline::latest_revision(&records) {
+            Some(revision) => {
+                exit_code = print_regressions(&records, revision, benchmarks, options.threshold);
+            },
+            None => println!(
+                "No baseline saved in \"{}\" yet, nothing to compare to",
+                options.file.display()
+            ),
+        },
+        Some(Compare::Revision(ref revision)) => {
+            if records.iter().any(|record| record.revision == *revision) {
+                exit_code = print_regressions(&records, revision, benchmarks, options.threshold);
+            } else {
+                eprintln!(
+                    "error: no baseline saved for revision `{}` in \"{}\"",
+                    revision,
+                    options.file.display()
+                );
+
+                exit_code = ExitCode::FAILURE;
+            }
+        },
+        None => {},
+    }
+
+    if options.save {
+        let revision = baseline::revision();
+
+        baseline::merge(&mut records, &revision, benchmarks);
+
+        match baseline::save(&options.file, &records) {
+            Ok(()) => println!(
+                "Saved {} timing(s) for revision `{}` to \"{}\"",
+                benchmarks.len(),
+                revision,
+                options.file.display()
+            ),
+            Err(error) => {
+                eprintln!("error: {}", error);
+
+                exit_code = ExitCode::FAILURE;
+            },
+        }
+    }
+
+    exit_code
+}
+
+fn print_regressions(
+    records: &[baseline::Record],
+    revision: &str,
+    benchmarks: &[Benchmark],
+    threshold: u32,
+) -> ExitCode {
+    let regressions = baseline::regressions(records, revision, benchmarks, threshold);
+
+    println!();
+
+    if regressions.is_empty() {
+        println!(
+            "No part got more than {}% slower than revision `{}`",
+            threshold, revision
+        );
+
+        return ExitCode::SUCCESS;
+    }
+
+    println!(
+        "{} part(s) got more than {}% slower than revision `{}`",
+        regressions.len(),
+        threshold,
+        revision
+    );
+    println!("{:<12}{:>12}{:>12}", "Puzzle/Part", "baseline", "median");
+
+    for regression in &regressions {
+        println!(
+            "{:<12}{:>12}{:>12}",
+            format!("{}/{}", regression.puzzle, regression.part),
+            format!("{:.2?}", regression.baseline),
+            format!("{:.2?}", regression.current)
+        );
+    }
+
+    ExitCode::FAILURE
+}
+
+/// Prints `benchmarks` as a table, slowest median first.
+fn print_overview(benchmarks: &[Benchmark]) {
+    let mut sorted = benchmarks.iter().collect::<Vec<_>>();
+
+    sorted.sort_by(|left, right| right.stats.median.cmp(&left.stats.median));
+
+    println!();
+    println!("{}", stats_header("Puzzle/Part"));
+
+    for benchmark in sorted {
+        println!(
+            "{}",
+            stats_row(
+                &format!("{}/{}", benchmark.puzzle, benchmark.part),
+                &benchmark.stats
+            )
+        );
+    }
+}
+
+fn stats_header(label: &str) -> String {
+    format!(
+        "{:<12}{:>12}{:>12}{:>12}{:>12}",
+        label, "min", "median", "mean", "p95"
+    )
+}
+
+fn stats_row(label: &str, stats: &Stats) -> String {
+    format!(
+        "{:<12}{:>12}{:>12}{:>12}{:>12}",
+        label,
+        format!("{:.2?}", stats.min),
+        format!("{:.2?}", stats.median),
+        format!("{:.2?}", stats.mean),
+        format!("{:.2?}", stats.p95)
+    )
+}
+
+/// The `main` of a day's binary. Solves both parts and, unless asked to only solve or given another
+/// input than the day's own, reports every answer that differs from the known answer.
+#[must_use]
+pub fn day_main(puzzle: PuzzleId, solution: &dyn Solver) -> ExitCode {
+    let day_args = match args::parse_day(env::args().skip(1)) {
+        Ok(day_args) => day_args,
+        Err(error) => {
+            eprintln!("error: {}\n\n{}", error, args::DAY_USAGE);
+
+            return ExitCode::FAILURE;
+        },
+    };
+
+    let answers_path = Answers::<Day>::path(puzzle.year());
+
+    let mut answers = match Answers::load(&answers_path) {
+        Ok(answers) => answers,
+        Err(error) => {
+            eprintln!("error: {}", error);
+
+            return ExitCode::FAILURE;
+        },
+    };
+
+    let input = match read_input(&day_args.input, &puzzle) {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("error: {}", error);
+
+            return ExitCode::FAILURE;
+        },
+    };
+
+    let mut tally = Tally::default();
+
+    solve_day(
+        puzzle,
+        solution,
+        &input,
+        &Part::ALL,
+        &day_args.params,
+        solve_mode(day_args.mode, &day_args.params, &day_args.input),
+        &mut answers,
+        &mut tally,
+    );
+
+    finish(&tally, &answers, &answers_path, None)
+}
+
+/// Solves `parts` of `puzzle` with `params`, printing the answers. Depending on `mode` answers are
+/// checked against, or recorded in, the `answers` of its year.
+#[expect(clippy::too_many_arguments, reason = "Shared by `run` and `day_main`")]
+fn solve_day(
+    puzzle: PuzzleId,
+    solution: &dyn Solver,
+    input: &str,
+    parts: &[Part],
+    params: &[Param],
+    mode: Mode,
+    answers: &mut Answers,
+    tally: &mut Tally,
+) {
+    println!("Day {}", puzzle);
+
+    let (parse_elapsed, solved) = match solve(solution, input, params, parts) {
+        Ok(solved) => solved,
+        Err(error) => {
+            print_error(puzzle, None, &error);
+
+            tally.errors += 1;
+            tally.results.extend(
+                parts
+                    .iter()
+                    .map(|&part| RunResult::error(puzzle, part, Duration::ZERO)),
+            );
+            return;
+        },
+    };
+
+    print_parse(parse_elapsed);
+
+    for answer in solved {
+        let solution = match answer.answer {
+            Ok(solution) => solution,
+            Err(error) => {
+                print_error(puzzle, Some(answer.part), &error);
+
+                tally.errors += 1;
+                tally
+                    .results
+                    .push(RunResult::error(puzzle, answer.part, answer.elapsed));
+                continue;
+            },
+        };
+
+        print_answer(answer.part, &solution, answer.elapsed);
+
+        let unreadable = matches!(solution, PartSolution::Manual(_)) && !is_known(&solution);
+
+        if let Some(text) = solution.read() {
+            println!("Part {} reads: {}", answer.part, text);
+        }
+
+        let outcome = match (mode, answers.get(puzzle.day(), answer.part)) {
+            (Mode::Solve, _) | (Mode::Verify, None) => Outcome::Unchecked,
+            (Mode::Verify | Mode::Record, Some(expected)) if unreadable => {
+                println!(
+                    "Part {}: compare the picture with the known answer {}",
+                    answer.part, expected
+                );
+
+                Outcome::Unchecked
+            },
+            (Mode::Verify | Mode::Record, Some(expected)) => {
+                if *expected == solution {
+                    Outcome::Passed
+                } else {
+                    print_mismatch(puzzle, answer.part, expected, &solution);
+
+                    tally.mismatches += 1;
+
+                    Outcome::Failed
+                }
+            },
+            (Mode::Record, None) => {
+                let recorded = if unreadable {
+                    ask(answer.part)
+                } else {
+                    Some(solution.clone())
+                };
+
+                if let Some(recorded) = recorded.filter(is_known) {
+                    answers.set(puzzle.day(), answer.part, recorded);
+
+                    tally.recorded += 1;
+                }
+
+                Outcome::Unchecked
+            },
+        };
+
+        tally.results.push(RunResult {
+            puzzle,
+            part: answer.part,
+            answer: Some(solution),
+            elapsed: answer.elapsed,
+            outcome,
+        });
+    }
+}
+
+/// The mode to solve in with `params` and `input`. The known answers are those of the actual
+/// puzzle, so with other parameters or another input than the day's own the answers are only
+/// solved.
+fn solve_mode(mode: Mode, params: &[Param], input: &Input) -> Mode {
+    if params.is_empty() && *input == Input::Data {
+        mode
+    } else {
+        Mode::Solve
+    }
+}
+
+/// Saves newly recorded answers, writes the results to the `json` file if there is one, and
+/// reports the totals of `tally`.
+fn finish(tally: &Tally, answers: &Answers, answers_path: &Path, json: Option<&Path>) -> ExitCode {
+    let mut failed = tally.errors > 0;
+
+    if let Some(json) = json {
+        if let Err(error) = write_json_lines(&tally.results, json) {
+            eprintln!("error: cannot write \"{}\": {}", json.display(), error);
+
+            failed = true;
+        }
+    }
+
+    if tally.recorded > 0 {
+        match answers.save(answers_path) {
+            Ok(()) => println!(
+                "Recorded {} new answer(s) in \"{}\"",
+                tally.recorded,
+                answers_path.display()
+            ),
+            Err(error) => {
+                eprintln!("error: {}", error);
+
+                failed = true;
+            },
+        }
+    }
+
+    if tally.mismatches > 0 {
+        eprintln!(
+            "{} part(s) did not match the known answer",
+            tally.mismatches
+        );
+
+        failed = true;
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Writes every result on its own line, as JSON.
+#[cfg(feature = "serde")]
+fn write_json_lines(results: &[RunResult], path: &Path) -> Result<(), io::Error> {
+    let mut writer = BufWriter::new(File::create(path)?);
+
+    for result in results {
+        serde_json::to_writer(&mut writer, result)?;
+
+        writeln!(writer)?;
+    }
+
+    writer.flush()
+}
+
+#[cfg(not(feature = "serde"))]
+fn write_json_lines(_results: &[RunResult], _path: &Path) -> Result<(), io::Error> {
+    Err(io::Error::other("built without the `serde` feature"))
+}
+
+/// Whether `solution` is an answer that can be recorded.
+fn is_known(solution: &PartSolution) -> bool {
+    if let PartSolution::Manual(_) = *solution {
+        solution.read().is_some()
+    } else {
+        solution.has_solution()
+    }
+}
+
+/// Asks for the answer drawn in the picture of `part`. Returns `None` when nothing was typed, or
+/// when there is no one to ask.
+fn ask(part: Part) -> Option<PartSolution> {
+    let stdin = io::stdin();
+
+    if !stdin.is_terminal() {
+        println!(
+            "Part {}: run interactively to type the answer the picture shows",
+            part
+        );
+
+        return None;
+    }
+
+    print!(
+        "Part {}: type the answer the picture shows, or nothing to skip: ",
+        part
+    );
+
+    io::stdout().flush().ok()?;
+
+    let mut line = String::new();
+
+    stdin.read_line(&mut line).ok()?;
+
+    let typed = line.trim();
+
+    (!typed.is_empty()).then(|| {
+        typed
+            .parse::<i128>()
+            .map_or_else(|_| typed.into(), PartSolution::I128)
+    })
+}
+
+fn print_parse(elapsed: Duration) {
+    println!("Parse: {:.2?}", elapsed);
+}
+
+fn print_answer(part: Part, answer: &PartSolution, elapsed: Duration) {
+    println!("Part {}: {} ({:.2?})", part, answer, elapsed);
+}
+
+fn print_error(puzzle: PuzzleId, part: Option<Part>, error: &SolveError) {
+    match part {
+        Some(part) => eprintln!("Day {}: part {}: {}", puzzle, part, error),
+        None => eprintln!("Day {}: {}", puzzle, error),
+    }
+}
+
+fn print_mismatch(puzzle: PuzzleId, part: Part, expected: &PartSolution, actual: &PartSolution) {
+    eprintln!(
+        "Day {}: part {} does not match the known answer",
+        puzzle, part
+    );
+    eprintln!("--- expected");
+    eprintln!("+++ actual");
+
+    if let PartSolution::Grid(ref grid) = *actual {
+        print_grid_diff(expected, &grid.lines());
+
+        return;
+    }
+
+    for line in expected.to_string().trim_start_matches('\n').lines() {
+        eprintln!("-{}", line);
+    }
+
+    for line in actual.to_string().trim_start_matches('\n').lines() {
+        eprintln!("+{}", line);
+    }
+}
+
+/// Prints the rows of a grid answer next to the expected ones, marking every cell that differs.
+fn print_grid_diff(expected: &PartSolution, actual: &[String]) {
+    let expected = expected.to_string();
+    let expected = expected
+        .trim_start_matches('\n')
+        .lines()
+        .collect::<Vec<_>>();
+
+    let mut differences = 0;
+
+    for index in 0..expected.len().max(actual.len()) {
+        let expected = expected.get(index).copied().unwrap_or_default();
+        let actual = actual.get(index).map(String::as_str).unwrap_or_default();
+
+        if expected == actual {
+            eprintln!(" {}", actual);
+
+            continue;
+        }
+
+        let expected_cells = expected.chars().collect::<Vec<_>>();
+        let actual_cells = actual.chars().collect::<Vec<_>>();
+
+        let markers = (0..expected_cells.len().max(actual_cells.len()))
+            .map(|column| {
+                if expected_cells.get(column) == actual_cells.get(column) {
+                    ' '
+                } else {
+                    differences += 1;
+
+                    '^'
+                }
+            })
+            .collect::<String>();
+
+        eprintln!("-{}", expected);
+        eprintln!("+{}", actual);
+        eprintln!(" {}", markers.trim_end());
+    }
+
+    eprintln!("{} cells differ", differences);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    #[cfg(feature = "serde")]
+    use std::time::Duration;
+
+    #[cfg(feature = "serde")]
+    use super::RunResult;
+    use super::{Mode, Outcome, Tally, solve_day, solve_mode};
+    #[cfg(feature = "serde")]
+    use crate::shared::PartSolution;
+    use crate::shared::answers::Answers;
+    use crate::shared::day::Day;
+    #[cfg(feature = "serde")]
+    use crate::shared::grids::grid::Grid;
+    use crate::shared::part::Part;
+    #[cfg(feature = "serde")]
+    use crate::shared::puzzle::PuzzleId;
+    use crate::shared::solution::{Input, read_input};
+    use crate::shared::year::Year;
+    use crate::{days, puzzle};
+
+    #[test]
+    fn example_input() {
+        let puzzle = puzzle!(2024, 7);
+        let example =
+            Input::Path(Path::new(env!("CARGO_MANIFEST_DIR")).join("data/2024/examples/07.txt"));
+
+        let mut answers = Answers::load(&Answers::<Day>::path(Year::DEFAULT)).unwrap();
+        let mut tally = Tally::default();
+
+        assert_eq!(Mode::Verify, solve_mode(Mode::Verify, &[], &Input::Data));
+        assert_eq!(Mode::Solve, solve_mode(Mode::Record, &[], &example));
+
+        solve_day(
+            puzzle,
+            days::get(puzzle).unwrap(),
+            &read_input(&example, &puzzle).unwrap(),
+            &Part::ALL,
+            &[],
+            solve_mode(Mode::Verify, &[], &example),
+            &mut answers,
+            &mut tally,
+        );
+
+        assert_eq!(0, tally.mismatches);
+        assert_eq!(0, tally.errors);
+        assert!(
+            tally
+                .results
+                .iter()
+                .all(|result| result.outcome == Outcome::Unchecked)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn json_lines() {
+        let result = RunResult {
+            puzzle: puzzle!(2024, 18),
+            part: Part::Two,
+            answer: Some(PartSolution::Coordinates(50, 23)),
+            elapsed: Duration::from_micros(1500),
+            outcome: Outcome::Passed,
+        };
+
+        let line = serde_json::to_string(&result).unwrap();
+
+        assert_eq!(
+            r#"{"puzzle":"2024/18","part":2,"answer":{"Coordinates":[50,23]},"elapsed":{"secs":0,"nanos":1500000},"outcome":"passed"}"#,
+            line
+        );
+        assert_eq!(result, serde_json::from_str(&line).unwrap());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn answers() {
+        let answers = [
+            PartSolution::U128(u128::MAX),
+            PartSolution::joined(vec!["co".into(), "de".into()], ","),
+            PartSolution::Grid(Grid::new(vec![vec!['#', '.'], vec!['.', '#']])),
+            PartSolution::None,
+        ];
+
+        for answer in answers {
+            let json = serde_json::to_string(&answer).unwrap();
+
+            assert_eq!(
+                answer,
+                serde_json::from_str::<PartSolution>(&json).unwrap(),
+                "{}",
+                json
+            );
+        }
+
+        assert_eq!(
+            r##"{"Grid":["#.",".#"]}"##,
+            serde_json::to_string(&PartSolution::Grid(Grid::new(vec![
+                vec!['#', '.'],
+                vec!['.', '#']
+            ])))
+            .unwrap()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn invalid() {
+        assert!(
+            serde_json::from_str::<Day>("26").is_err(),
+            "Day 26 is not a day"
+        );
+        assert!(
+            serde_json::from_str::<PuzzleId>(r#""2025/13""#).is_err(),
+            "2025 has 12 days"
+        );
+        assert!(
+            serde_json::from_str::<Part>("3").is_err(),
+            "Part 3 is not a part"
+        );
+        assert!(
+            serde_json::from_str::<Grid<char>>(r##"["#.", "#"]"##).is_err(),
+            "Ragged rows are not a grid"
+        );
+        assert!(
+            serde_json::from_str::<Grid<char>>(r#"["", ""]"#).is_err(),
+            "Empty rows are not a grid"
+        );
+    }
+}
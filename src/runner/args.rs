@@ -0,0 +1,892 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use super::baseline::{BaselineOptions, Compare};
+use super::bench::Options;
+use super::scaffold::{NewDay, Template};
+use crate::shared::day_set::DaySet;
+use crate::shared::params::Param;
+use crate::shared::part::Part;
+use crate::shared::puzzle::PuzzleId;
+use crate::shared::solution::Input;
+use crate::shared::year::Year;
+
+pub const USAGE: &str = "\
+Usage: aoc <COMMAND>
+
+Commands:
+  run <DAYS> [--part <PART>]    Solves the selected days and prints the answers
+        [--year <YEAR>]         The event the days are of (default 2024)
+        [--verify | --solve | --record]
+                                Checks the answers against the known answers (default), only
+                                prints them, or also records the missing known answers
+        [--param <NAME=VALUE>]  Overrides a parameter of the puzzle, like the size of a grid.
+                                The answers aren't checked against the known answers
+        [--json <FILE>]         Also writes the result of every part to the file, as JSON
+                                lines. Needs the `serde` feature
+  bench <DAYS> [--part <PART>]  Times the selected days and prints a summary
+        [--year <YEAR>]         The event the days are of
+        [--param <NAME=VALUE>]  Overrides a parameter of the puzzle
+        [--runs <N>]            The number of measured runs (default 10)
+        [--warmup <N>]          The number of runs before measuring (default 1)
+        [--save]                Saves the timings under the current git revision
+        [--compare]             Compares the medians to the revision saved last, and fails
+                                when a part got slower than the threshold
+        [--baseline <REV>]      Compares to the given revision instead
+        [--threshold <PERCENT>] How much slower a part may get (default 10)
+        [--baseline-file <FILE>]
+                                Where the timings are saved (default bench-baselines.json).
+                                Baselines need the `serde` feature
+  list [--year <YEAR>]          Lists the days of the event, with the title and status of their
+                                solution
+  new <DAY>                     Creates the solution of the day, from a template, and its empty
+                                input and examples with placeholder answers
+        [--year <YEAR>]         The event the day is of (default 2024)
+        [--template <TEMPLATE>] `plain` (default), `grid` or `graph`
+        [--title <TITLE>]       The title of the puzzle
+        [--examples <N>]        The number of examples (default 1)
+  help                          Prints this message
+
+Days, separated by commas like `1-5,7,20-`:
+  7                             A single day
+  3-9 or 3..=9                  Days 3 up to and including 9
+  3..9                          Days 3 up to, but not including, 9
+  20-                           Day 20 up to the last day of the event
+  all or --all                  Every day of the event, 25 until 2024 and 12 from 2025 on
+  odd, even                     The odd or even days
+  grids, pathfinding, graphs, simulation, math, parsing, dynamic-programming
+                                The days whose solution has the tag";
+
+pub const DAY_USAGE: &str = "\
+Usage: <DAY> [--verify | --solve | --record] [--param <NAME=VALUE>]... [INPUT]
+
+Arguments:
+  INPUT     The file to read the input from, `-` for standard input.
+            Defaults to the day's file in `$AOC_DATA_DIR/<YEAR>/inputs` or `data/<YEAR>/inputs`
+
+Options:
+  --verify  Solves both parts and checks them against the known answers (default)
+  --solve   Solves both parts without checking them
+  --record  Solves both parts, checks them and records the answers that aren't known yet. Only
+            with the day's own input
+  --param   Overrides a parameter of the puzzle, like the size of a grid. The answers aren't
+            checked against the known answers";
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    Run {
+        year: Year,
+        days: DaySet,
+        parts: Vec<Part>,
+        params: Vec<Param>,
+        mode: Mode,
+        json: Option<PathBuf>,
+    },
+    Bench {
+        year: Year,
+        days: DaySet,
+        parts: Vec<Part>,
+        params: Vec<Param>,
+        options: Options,
+        baseline: BaselineOptions,
+    },
+    List {
+        year: Year,
+    },
+    New(NewDay),
+    Help,
+}
+
+/// What is done with the answers after solving.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// Compare the answers with the known answers.
+    #[default]
+    Verify,
+    /// Only print the answers.
+    Solve,
+    /// Compare the answers with the known answers, and add those that aren't known yet.
+    Record,
+}
+
+impl Mode {
+    fn from_flag(flag: &str) -> Option<Mode> {
+        match flag {
+            "--verify" => Some(Mode::Verify),
+            "--solve" => Some(Mode::Solve),
+            "--record" => Some(Mode::Record),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct DayArgs {
+    pub mode: Mode,
+    pub params: Vec<Param>,
+    pub input: Input,
+}
+
+/// Parses the command line arguments of a day's binary, without the program name.
+///
+/// # Errors
+/// When the arguments don't form valid [`DayArgs`]
+pub fn parse_day<I>(args: I) -> Result<DayArgs, ArgsError>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut args = args.into_iter();
+
+    let mut day_args = DayArgs::default();
+
+    while let Some(arg) = args.next() {
+        if let Some(mode) = Mode::from_flag(&arg) {
+            day_args.mode = mode;
+        } else if arg == "--param" {
+            day_args.params.push(parse_param(args.next())?);
+        } else if day_args.input == Input::Data && (arg == "-" || !arg.starts_with('-')) {
+            let Ok(input) = arg.parse();
+
+            day_args.input = input;
+        } else {
+            return Err(ArgsError::UnexpectedArgument(arg));
+        }
+    }
+
+    if day_args.mode == Mode::Record && !day_args.params.is_empty() {
+        return Err(ArgsError::RecordWithParams);
+    }
+
+    if day_args.mode == Mode::Record && day_args.input != Input::Data {
+        return Err(ArgsError::RecordWithInput);
+    }
+
+    Ok(day_args)
+}
+
+/// Parses the command line arguments, without the program name.
+///
+/// # Errors
+/// When the arguments don't form a valid [`Command`]
+pub fn parse<I>(args: I) -> Result<Command, ArgsError>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut args = args.into_iter();
+
+    match args.next().as_deref() {
+        Some("run") => {
+            let mut mode = Mode::default();
+            let mut json = None;
+
+            let Selection {
+                year,
+                days,
+                parts,
+                params,
+            } = parse_selection(args, |arg, args| {
+                if arg == "--json" {
+                    json = Some(args.next().ok_or(ArgsError::MissingValue("--json"))?.into());
+
+                    return Ok(true);
+                }
+
+                Ok(Mode::from_flag(arg).map(|flag| mode = flag).is_some())
+            })?;
+
+            if mode == Mode::Record && !params.is_empty() {
+                return Err(ArgsError::RecordWithParams);
+            }
+
+            Ok(Command::Run {
+                year,
+                days,
+                parts,
+                params,
+                mode,
+                json,
+            })
+        },
+        Some("bench") => {
+            let mut options = Options::default();
+            let mut baseline = BaselineOptions::default();
+
+            let Selection {
+                year,
+                days,
+                parts,
+                params,
+            } = parse_selection(args, |arg, args| {
+                match arg {
+                    "--runs" => {
+                        options.runs = parse_count(args.next(), "--runs")?;
+
+                        if options.runs == 0 {
+                            return Err(ArgsError::InvalidCount("0".into()));
+                        }
+                    },
+                    "--warmup" => options.warmup = parse_count(args.next(), "--warmup")?,
+                    "--save" => baseline.save = true,
+                    "--compare" => baseline.compare = Some(Compare::Latest),
+                    "--baseline" => {
+                        let revision = args.next().ok_or(ArgsError::MissingValue("--baseline"))?;
+
+                        baseline.compare = Some(Compare::Revision(revision));
+                    },
+                    "--threshold" => {
+                        baseline.threshold = parse_count(args.next(), "--threshold")?;
+                    },
+                    "--baseline-file" => {
+                        baseline.file = args
+                            .next()
+                            .ok_or(ArgsError::MissingValue("--baseline-file"))?
+                            .into();
+                    },
+                    _ => return Ok(false),
+                }
+
+                Ok(true)
+            })?;
+
+            if cfg!(not(feature = "serde")) && (baseline.save || baseline.compare.is_some()) {
+                return Err(ArgsError::BaselineWithoutSerde);
+            }
+
+            Ok(Command::Bench {
+                year,
+                days,
+                parts,
+                params,
+                options,
+                baseline,
+            })
+        },
+        Some("list") => {
+            let mut year = Year::DEFAULT;
+
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--year" | "-y" => year = parse_year(args.next())?,
+                    _ => return Err(ArgsError::UnexpectedArgument(arg)),
+                }
+            }
+
+            Ok(Command::List { year })
+        },
+        Some("new") => parse_new(args).map(Command::New),
+        Some("help" | "--help" | "-h") => Ok(Command::Help),
+        Some(other) => Err(ArgsError::UnknownCommand(other.to_owned())),
+        None => Err(ArgsError::MissingCommand),
+    }
+}
+
+/// The days and parts to run, and the parameters to run them with.
+struct Selection {
+    year: Year,
+    days: DaySet,
+    parts: Vec<Part>,
+    params: Vec<Param>,
+}
+
+/// Parses the [`Selection`]. Any other option is first offered to `command_option`, which returns
+/// whether it was one of the command's own.
+fn parse_selection<I, F>(mut args: I, mut command_option: F) -> Result<Selection, ArgsError>
+where
+    I: Iterator<Item = String>,
+    F: FnMut(&str, &mut I) -> Result<bool, ArgsError>,
+{
+    let mut year = Year::DEFAULT;
+    let mut days = None;
+    let mut parts = Part::ALL.to_vec();
+    let mut params = vec![];
+
+    while let Some(arg) = args.next() {
+        if command_option(&arg, &mut args)? {
+            continue;
+        }
+
+        match arg.as_str() {
+            "--part" | "-p" => {
+                let part = args.next().ok_or(ArgsError::MissingPart)?;
+
+                parts = vec![part.parse().map_err(|_| ArgsError::InvalidPart(part))?];
+            },
+            "--param" => params.push(parse_param(args.next())?),
+            "--year" | "-y" => year = parse_year(args.next())?,
+            "--all" | "-a" if days.is_none() => days = Some(DaySet::all()),
+            _ if days.is_none() && !arg.starts_with('-') => {
+                days = Some(arg.parse().map_err(|_| ArgsError::InvalidDays(arg))?);
+            },
+            _ => return Err(ArgsError::UnexpectedArgument(arg)),
+        }
+    }
+
+    let days = days.ok_or(ArgsError::MissingDays)?;
+
+    // the days can only be checked once the year is known
+    if !days.fits(year) {
+        return Err(ArgsError::InvalidDays(days.to_string()));
+    }
+
+    Ok(Selection {
+        year,
+        days,
+        parts,
+        params,
+    })
+}
+
+/// Parses the day to create and how, the arguments of `new`.
+fn parse_new<I>(mut args: I) -> Result<NewDay, ArgsError>
+where
+    I: Iterator<Item = String>,
+{
+    let mut year = Year::DEFAULT;
+    let mut day = None;
+    let mut template = Template::default();
+    let mut title = None;
+    let mut examples = 1;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--template" => {
+                let arg = args.next().ok_or(ArgsError::MissingValue("--template"))?;
+
+                template = arg.parse().map_err(|_| ArgsError::InvalidTemplate(arg))?;
+            },
+            "--title" => {
+                title = Some(args.next().ok_or(ArgsError::MissingValue("--title"))?);
+            },
+            "--examples" => examples = parse_count(args.next(), "--examples")?,
+            "--year" | "-y" => year = parse_year(args.next())?,
+            _ if day.is_none() && !arg.starts_with('-') => {
+                day = Some(arg.parse().map_err(|_| ArgsError::InvalidDays(arg))?);
+            },
+            _ => return Err(ArgsError::UnexpectedArgument(arg)),
+        }
+    }
+
+    let day = day.ok_or(ArgsError::MissingDays)?;
+
+    Ok(NewDay {
+        puzzle: PuzzleId::try_new(year, day)
+            .ok_or_else(|| ArgsError::InvalidDays(day.to_string()))?,
+        template,
+        title,
+        examples,
+    })
+}
+
+fn parse_year(arg: Option<String>) -> Result<Year, ArgsError> {
+    let arg = arg.ok_or(ArgsError::MissingValue("--year"))?;
+
+    arg.parse().map_err(|_| ArgsError::InvalidYear(arg))
+}
+
+fn parse_param(arg: Option<String>) -> Result<Param, ArgsError> {
+    let arg = arg.ok_or(ArgsError::MissingValue("--param"))?;
+
+    arg.parse().map_err(|_| ArgsError::InvalidParam(arg))
+}
+
+fn parse_count<T: FromStr>(arg: Option<String>, option: &'static str) -> Result<T, ArgsError> {
+    let arg = arg.ok_or(ArgsError::MissingValue(option))?;
+
+    arg.parse().map_err(|_| ArgsError::InvalidCount(arg))
+}
+
+/// An error which can be returned when parsing the command line arguments.
+#[expect(clippy::module_name_repetitions, reason = "Name clarity")]
+#[derive(Debug, PartialEq, Eq)]
+pub enum ArgsError {
+    MissingCommand,
+    UnknownCommand(String),
+    MissingDays,
+    InvalidDays(String),
+    InvalidYear(String),
+    MissingPart,
+    InvalidPart(String),
+    MissingValue(&'static str),
+    InvalidCount(String),
+    InvalidParam(String),
+    InvalidTemplate(String),
+    RecordWithParams,
+    RecordWithInput,
+    BaselineWithoutSerde,
+    UnexpectedArgument(String),
+}
+
+impl Error for ArgsError {}
+
+impl Display for ArgsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            ArgsError::MissingCommand => f.write_str("missing command"),
+            ArgsError::UnknownCommand(ref command) => write!(f, "unknown command `{}`", command),
+            ArgsError::MissingDays => f.write_str("missing the days to run"),
+            ArgsError::InvalidDays(ref days) => write!(
+                f,
+                "invalid days `{}`, expecting days of the event, between 1 and 25 (12 from 2025 on), ranges like `1-5` or `20-`, `all`, `odd`, `even` or tags",
+                days
+            ),
+            ArgsError::InvalidYear(ref year) => {
+                write!(f, "invalid year `{}`, expecting 2015 or later", year)
+            },
+            ArgsError::MissingPart => f.write_str("missing the value for `--part`"),
+            ArgsError::InvalidPart(ref part) => {
+                write!(f, "invalid part `{}`, expecting either 1 or 2", part)
+            },
+            ArgsError::MissingValue(option) => write!(f, "missing the value for `{}`", option),
+            ArgsError::InvalidCount(ref count) => {
+                write!(f, "invalid count `{}`, expecting a positive number", count)
+            },
+            ArgsError::InvalidParam(ref param) => {
+                write!(f, "invalid parameter `{}`, expecting `NAME=VALUE`", param)
+            },
+            ArgsError::InvalidTemplate(ref template) => write!(
+                f,
+                "invalid template `{}`, expecting `plain`, `grid` or `graph`",
+                template
+            ),
+            ArgsError::RecordWithParams => f.write_str(
+                "`--record` cannot be combined with `--param`, the known answers are those of the actual puzzle",
+            ),
+            ArgsError::RecordWithInput => f.write_str(
+                "`--record` cannot be combined with an input, the known answers are those of the day's own input",
+            ),
+            ArgsError::BaselineWithoutSerde => f.write_str(
+                "saving and comparing baselines needs the `serde` feature, build with `--features serde`",
+            ),
+            ArgsError::UnexpectedArgument(ref argument) => {
+                write!(f, "unexpected argument `{}`", argument)
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ArgsError, Command, DayArgs, Mode, parse, parse_day};
+    use crate::runner::baseline::BaselineOptions;
+    #[cfg(feature = "serde")]
+    use crate::runner::baseline::Compare;
+    use crate::runner::bench::Options;
+    use crate::runner::scaffold::{NewDay, Template};
+    use crate::shared::day_set::DaySet;
+    use crate::shared::part::Part;
+    use crate::shared::solution::Input;
+    use crate::shared::year::Year;
+    use crate::{day, puzzle};
+
+    fn args(args: &str) -> Vec<String> {
+        args.split_whitespace().map(ToOwned::to_owned).collect()
+    }
+
+    #[test]
+    fn run_single_day() {
+        assert_eq!(
+            Ok(Command::Run {
+                year: Year::DEFAULT,
+                days: DaySet::from(day!(7)..=day!(7)),
+                parts: vec![Part::One, Part::Two],
+                params: vec![],
+                mode: Mode::Verify,
+                json: None
+            }),
+            parse(args("run 7"))
+        );
+    }
+
+    #[test]
+    fn run_all() {
+        assert_eq!(
+            Ok(Command::Run {
+                year: Year::DEFAULT,
+                days: DaySet::all(),
+                parts: vec![Part::One, Part::Two],
+                params: vec![],
+                mode: Mode::Record,
+                json: None
+            }),
+            parse(args("run --all --record"))
+        );
+    }
+
+    #[test]
+    fn run_range_with_part() {
+        assert_eq!(
+            Ok(Command::Run {
+                year: Year::DEFAULT,
+                days: DaySet::from(day!(3)..=day!(9)),
+                parts: vec![Part::Two],
+                params: vec![],
+                mode: Mode::Verify,
+                json: None
+            }),
+            parse(args("run 3..=9 --part 2"))
+        );
+
+        assert_eq!(
+            Ok(Command::Run {
+                year: Year::DEFAULT,
+                days: DaySet::from(day!(3)..=day!(8)),
+                parts: vec![Part::One],
+                params: vec![],
+                mode: Mode::Solve,
+                json: None
+            }),
+            parse(args("run 3..9 -p 1 --solve"))
+        );
+
+        assert_eq!(
+            Ok(Command::Run {
+                year: Year::DEFAULT,
+                days: DaySet::from(day!(3)..=day!(3)),
+                parts: vec![Part::One, Part::Two],
+                params: vec![],
+                mode: Mode::Verify,
+                json: Some("results.jsonl".into())
+            }),
+            parse(args("run 3 --json results.jsonl"))
+        );
+
+        assert_eq!(
+            Ok(Command::Run {
+                year: Year::DEFAULT,
+                days: "1-5,7,grids,20-".parse().unwrap(),
+                parts: vec![Part::One, Part::Two],
+                params: vec![],
+                mode: Mode::Verify,
+                json: None
+            }),
+            parse(args("run 1-5,7,grids,20-"))
+        );
+    }
+
+    #[test]
+    fn bench() {
+        assert_eq!(
+            Ok(Command::Bench {
+                year: Year::DEFAULT,
+                days: DaySet::all(),
+                parts: vec![Part::One, Part::Two],
+                params: vec![],
+                options: Options::default(),
+                baseline: BaselineOptions::default()
+            }),
+            parse(args("bench --all"))
+        );
+
+        assert_eq!(
+            Ok(Command::Bench {
+                year: Year::DEFAULT,
+                days: DaySet::from(day!(6)..=day!(6)),
+                parts: vec![Part::Two],
+                params: vec![],
+                options: Options {
+                    runs: 50,
+                    warmup: 0
+                },
+                baseline: BaselineOptions::default()
+            }),
+            parse(args("bench 6 --runs 50 --warmup 0 -p 2"))
+        );
+
+        assert_eq!(
+            Err(ArgsError::InvalidCount("many".into())),
+            parse(args("bench 6 --runs many"))
+        );
+        assert_eq!(
+            Err(ArgsError::InvalidCount("0".into())),
+            parse(args("bench 6 --runs 0"))
+        );
+        assert_eq!(
+            Err(ArgsError::MissingValue("--warmup")),
+            parse(args("bench 6 --warmup"))
+        );
+        assert_eq!(
+            Err(ArgsError::UnexpectedArgument("--runs".into())),
+            parse(args("run 6 --runs 5"))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn bench_baseline() {
+        assert_eq!(
+            Ok(Command::Bench {
+                year: Year::DEFAULT,
+                days: DaySet::from(day!(4)..=day!(20)),
+                parts: vec![Part::One, Part::Two],
+                params: vec![],
+                options: Options::default(),
+                baseline: BaselineOptions {
+                    file: "baselines.json".into(),
+                    save: true,
+                    compare: Some(Compare::Revision("abc1234".into())),
+                    threshold: 25
+                }
+            }),
+            parse(args(
+                "bench 4..=20 --save --baseline abc1234 --threshold 25 --baseline-file baselines.json"
+            ))
+        );
+
+        assert_eq!(
+            Ok(Command::Bench {
+                year: Year::DEFAULT,
+                days: DaySet::from(day!(6)..=day!(6)),
+                parts: vec![Part::One, Part::Two],
+                params: vec![],
+                options: Options::default(),
+                baseline: BaselineOptions {
+                    compare: Some(Compare::Latest),
+                    ..BaselineOptions::default()
+                }
+            }),
+            parse(args("bench 6 --compare"))
+        );
+
+        assert_eq!(
+            Err(ArgsError::UnexpectedArgument("--save".into())),
+            parse(args("run 6 --save"))
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "serde"))]
+    fn bench_baseline_without_serde() {
+        assert_eq!(
+            Err(ArgsError::BaselineWithoutSerde),
+            parse(args("bench 6 --save"))
+        );
+        assert_eq!(
+            Err(ArgsError::BaselineWithoutSerde),
+            parse(args("bench 6 --baseline abc1234"))
+        );
+    }
+
+    #[test]
+    fn years() {
+        let year = Year::try_new(2025).unwrap();
+
+        assert_eq!(
+            Ok(Command::Run {
+                year,
+                days: DaySet::all(),
+                parts: vec![Part::One, Part::Two],
+                params: vec![],
+                mode: Mode::Verify,
+                json: None
+            }),
+            parse(args("run --all --year 2025"))
+        );
+        assert_eq!(
+            Ok(Command::Bench {
+                year,
+                days: DaySet::from(day!(3)..=day!(3)),
+                parts: vec![Part::One, Part::Two],
+                params: vec![],
+                options: Options::default(),
+                baseline: BaselineOptions::default(),
+            }),
+            parse(args("bench -y 2025 3"))
+        );
+    }
+
+    #[test]
+    fn list() {
+        assert_eq!(
+            Ok(Command::List {
+                year: Year::DEFAULT
+            }),
+            parse(args("list"))
+        );
+        assert_eq!(
+            Ok(Command::List {
+                year: Year::try_new(2025).unwrap()
+            }),
+            parse(args("list --year 2025"))
+        );
+        assert_eq!(
+            Err(ArgsError::UnexpectedArgument("7".into())),
+            parse(args("list 7"))
+        );
+    }
+
+    #[test]
+    fn new() {
+        assert_eq!(
+            Ok(Command::New(NewDay {
+                puzzle: puzzle!(2024, 7),
+                template: Template::Plain,
+                title: None,
+                examples: 1
+            })),
+            parse(args("new 7"))
+        );
+        assert_eq!(
+            Ok(Command::New(NewDay {
+                puzzle: puzzle!(2024, 16),
+                template: Template::Grid,
+                title: Some("Maze".into()),
+                examples: 2
+            })),
+            parse(args("new --template grid 16 --examples 2 --title Maze"))
+        );
+        assert_eq!(
+            Err(ArgsError::InvalidTemplate("tree".into())),
+            parse(args("new 7 --template tree"))
+        );
+        assert_eq!(
+            Err(ArgsError::InvalidDays("26".into())),
+            parse(args("new 26"))
+        );
+        assert_eq!(
+            Ok(Command::New(NewDay {
+                puzzle: puzzle!(2025, 7),
+                template: Template::Plain,
+                title: None,
+                examples: 1
+            })),
+            parse(args("new 7 --year 2025"))
+        );
+        assert_eq!(
+            Err(ArgsError::InvalidDays("13".into())),
+            parse(args("new -y 2025 13"))
+        );
+        assert_eq!(Err(ArgsError::MissingDays), parse(args("new")));
+    }
+
+    #[test]
+    fn params() {
+        assert_eq!(
+            Ok(Command::Run {
+                year: Year::DEFAULT,
+                days: DaySet::from(day!(14)..=day!(14)),
+                parts: vec![Part::One, Part::Two],
+                params: vec!["width=11".parse().unwrap(), "height=7".parse().unwrap()],
+                mode: Mode::Verify,
+                json: None
+            }),
+            parse(args("run 14 --param width=11 --param height=7"))
+        );
+        assert_eq!(
+            Ok(DayArgs {
+                mode: Mode::Solve,
+                params: vec!["bytes=12".parse().unwrap()],
+                input: Input::Path("example.txt".into())
+            }),
+            parse_day(args("--solve --param bytes=12 example.txt"))
+        );
+        assert_eq!(
+            Err(ArgsError::InvalidParam("width".into())),
+            parse(args("bench 14 --param width"))
+        );
+        assert_eq!(
+            Err(ArgsError::MissingValue("--param")),
+            parse_day(args("--param"))
+        );
+        assert_eq!(
+            Err(ArgsError::RecordWithParams),
+            parse(args("run 14 --record --param width=11"))
+        );
+        assert_eq!(
+            Err(ArgsError::RecordWithParams),
+            parse_day(args("--param width=11 --record"))
+        );
+        assert_eq!(
+            Err(ArgsError::RecordWithInput),
+            parse_day(args("--record example.txt"))
+        );
+        assert_eq!(
+            Err(ArgsError::RecordWithInput),
+            parse_day(args("- --record"))
+        );
+        assert_eq!(
+            Err(ArgsError::UnexpectedArgument("example.txt".into())),
+            parse(args("run 7 --record example.txt"))
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(Err(ArgsError::MissingCommand), parse(args("")));
+        assert_eq!(Err(ArgsError::MissingDays), parse(args("run")));
+        assert_eq!(
+            Err(ArgsError::InvalidDays("9..3".into())),
+            parse(args("run 9..3"))
+        );
+        assert_eq!(
+            Err(ArgsError::InvalidDays("1..1".into())),
+            parse(args("run 1..1"))
+        );
+        assert_eq!(
+            Err(ArgsError::InvalidPart("3".into())),
+            parse(args("run 1 --part 3"))
+        );
+        assert_eq!(
+            Err(ArgsError::InvalidYear("2014".into())),
+            parse(args("run 1 --year 2014"))
+        );
+        assert_eq!(
+            Err(ArgsError::InvalidDays("10-13".into())),
+            parse(args("run 10..=13 --year 2025"))
+        );
+        assert_eq!(
+            Err(ArgsError::UnexpectedArgument("2".into())),
+            parse(args("run 1 2"))
+        );
+    }
+
+    #[test]
+    fn day_mode() {
+        assert_eq!(Ok(DayArgs::default()), parse_day(args("")));
+        assert_eq!(
+            Ok(DayArgs {
+                mode: Mode::Solve,
+                params: vec![],
+                input: Input::Data
+            }),
+            parse_day(args("--solve"))
+        );
+        assert_eq!(
+            Err(ArgsError::UnexpectedArgument("--fast".into())),
+            parse_day(args("--fast"))
+        );
+    }
+
+    #[test]
+    fn day_input() {
+        assert_eq!(
+            Ok(DayArgs {
+                mode: Mode::Verify,
+                params: vec![],
+                input: Input::Path("input.txt".into())
+            }),
+            parse_day(args("input.txt"))
+        );
+        assert_eq!(
+            Ok(DayArgs {
+                mode: Mode::Solve,
+                params: vec![],
+                input: Input::Stdin
+            }),
+            parse_day(args("- --solve"))
+        );
+        assert_eq!(
+            Ok(DayArgs {
+                mode: Mode::Record,
+                params: vec![],
+                input: Input::Data
+            }),
+            parse_day(args("--record"))
+        );
+        assert_eq!(
+            Err(ArgsError::UnexpectedArgument("other.txt".into())),
+            parse_day(args("input.txt other.txt"))
+        );
+    }
+}
@@ -0,0 +1,502 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Duration;
+#[cfg(feature = "serde")]
+use std::{fs, io::ErrorKind};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::bench::{Benchmark, Stats};
+#[cfg(feature = "serde")]
+use crate::shared::day::Day;
+use crate::shared::part::Part;
+use crate::shared::puzzle::PuzzleId;
+#[cfg(feature = "serde")]
+use crate::shared::year::Year;
+
+/// Where baselines are stored when no other file is given.
+pub const DEFAULT_FILE: &str = "bench-baselines.json";
+
+/// How much slower, in percent, a part may get before it counts as a regression.
+pub const DEFAULT_THRESHOLD: u32 = 10;
+
+/// Which saved revision to compare against.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Compare {
+    /// The revision that was saved last.
+    Latest,
+    /// A specific revision.
+    Revision(String),
+}
+
+/// What to do with the baselines after benchmarking.
+#[expect(clippy::module_name_repetitions, reason = "Name clarity")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BaselineOptions {
+    pub file: PathBuf,
+    /// Store the timings under the current revision.
+    pub save: bool,
+    pub compare: Option<Compare>,
+    /// See [`DEFAULT_THRESHOLD`].
+    pub threshold: u32,
+}
+
+impl Default for BaselineOptions {
+    fn default() -> Self {
+        BaselineOptions {
+            file: DEFAULT_FILE.into(),
+            save: false,
+            compare: None,
+            threshold: DEFAULT_THRESHOLD,
+        }
+    }
+}
+
+/// The timings of one part of one puzzle at one revision.
+///
+/// Serializes as a flat object, with the timings in nanoseconds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(into = "StoredRecord", try_from = "StoredRecord")
+)]
+pub struct Record {
+    pub revision: String,
+    pub puzzle: PuzzleId,
+    pub part: Part,
+    pub stats: Stats,
+}
+
+/// A part whose median got slower than the threshold allows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Regression {
+    pub puzzle: PuzzleId,
+    pub part: Part,
+    pub baseline: Duration,
+    pub current: Duration,
+}
+
+/// The git revision of this crate's working tree, with a `-dirty` suffix when there are uncommitted
+/// changes, or `unknown` when git isn't available.
+#[must_use]
+pub fn revision() -> String {
+    fn git(args: &[&str]) -> Option<String> {
+        let output = Command::new("git")
+            .args(args)
+            .current_dir(env!("CARGO_MANIFEST_DIR"))
+            .output()
+            .ok()?;
+
+        if !output.status.success() {
+            return None;
+        }
+
+        String::from_utf8(output.stdout).ok()
+    }
+
+    let Some(head) = git(&["rev-parse", "--short", "HEAD"]) else {
+        return "unknown".into();
+    };
+
+    let dirty = git(&["status", "--porcelain", "--untracked-files=no"])
+        .is_some_and(|status| !status.trim().is_empty());
+
+    if dirty {
+        format!("{}-dirty", head.trim())
+    } else {
+        head.trim().into()
+    }
+}
+
+/// Reads the records in `path`. A file that doesn't exist has no records.
+///
+/// # Errors
+/// When the file cannot be read, or isn't a baseline file
+#[cfg(feature = "serde")]
+pub fn load(path: &Path) -> Result<Vec<Record>, BaselineError> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(error) if error.kind() == ErrorKind::NotFound => return Ok(vec![]),
+        Err(error) => return Err(BaselineError::Io(path.to_owned(), error)),
+    };
+
+    serde_json::from_str(&contents)
+        .map_err(|error| BaselineError::Malformed(path.to_owned(), error))
+}
+
+/// Baselines are stored as JSON, which needs the `serde` feature.
+///
+/// # Errors
+/// Always
+#[cfg(not(feature = "serde"))]
+pub fn load(path: &Path) -> Result<Vec<Record>, BaselineError> {
+    Err(BaselineError::Unsupported(path.to_owned()))
+}
+
+/// Writes `records` to `path`.
+///
+/// # Errors
+/// When the file cannot be written
+#[cfg(feature = "serde")]
+pub fn save(path: &Path, records: &[Record]) -> Result<(), BaselineError> {
+    let mut json = serde_json::to_string_pretty(records)
+        .map_err(|error| BaselineError::Malformed(path.to_owned(), error))?;
+
+    json.push('\n');
+
+    fs::write(path, json).map_err(|error| BaselineError::Io(path.to_owned(), error))
+}
+
+/// Baselines are stored as JSON, which needs the `serde` feature.
+///
+/// # Errors
+/// Always
+#[cfg(not(feature = "serde"))]
+pub fn save(path: &Path, _records: &[Record]) -> Result<(), BaselineError> {
+    Err(BaselineError::Unsupported(path.to_owned()))
+}
+
+/// Adds `benchmarks` to `records` under `revision`, replacing what was there for the same puzzle
+/// and part.
+pub fn merge(records: &mut Vec<Record>, revision: &str, benchmarks: &[Benchmark]) {
+    records.retain(|record| {
+        record.revision != revision
+            || !benchmarks
+                .iter()
+                .any(|benchmark| benchmark.puzzle == record.puzzle && benchmark.part == record.part)
+    });
+
+    records.extend(benchmarks.iter().map(|benchmark| Record {
+        revision: revision.into(),
+        puzzle: benchmark.puzzle,
+        part: benchmark.part,
+        stats: benchmark.stats,
+    }));
+}
+
+/// The revision that was saved last.
+#[must_use]
+pub fn latest_revision(records: &[Record]) -> Option<&str> {
+    records.last().map(|record| record.revision.as_str())
+}
+
+/// Compares the medians of `benchmarks` to those of `revision` in `records`. Parts that weren't
+/// saved for `revision` are skipped.
+#[must_use]
+pub fn regressions(
+    records: &[Record],
+    revision: &str,
+    benchmarks: &[Benchmark],
+    threshold: u32,
+) -> Vec<Regression> {
+    benchmarks
+        .iter()
+        .filter_map(|benchmark| {
+            let record = records.iter().find(|record| {
+                record.revision == revision
+                    && record.puzzle == benchmark.puzzle
+                    && record.part == benchmark.part
+            })?;
+
+            let baseline = record.stats.median;
+            let current = benchmark.stats.median;
+
+            let slower =
+                current.as_nanos() * 100 > baseline.as_nanos() * (100 + u128::from(threshold));
+
+            slower.then_some(Regression {
+                puzzle: benchmark.puzzle,
+                part: benchmark.part,
+                baseline,
+                current,
+            })
+        })
+        .collect()
+}
+
+/// A [`Record`] as it is stored in a baseline file.
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+struct StoredRecord {
+    revision: String,
+    /// Files saved before there were several years only have this crate's year.
+    #[serde(default = "default_year")]
+    year: Year,
+    day: Day,
+    part: Part,
+    min_ns: u64,
+    median_ns: u64,
+    mean_ns: u64,
+    p95_ns: u64,
+}
+
+#[cfg(feature = "serde")]
+const fn default_year() -> Year {
+    Year::DEFAULT
+}
+
+#[cfg(feature = "serde")]
+impl From<Record> for StoredRecord {
+    fn from(record: Record) -> Self {
+        let nanos = |duration: Duration| u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX);
+
+        StoredRecord {
+            revision: record.revision,
+            year: record.puzzle.year(),
+            day: record.puzzle.day(),
+            part: record.part,
+            min_ns: nanos(record.stats.min),
+            median_ns: nanos(record.stats.median),
+            mean_ns: nanos(record.stats.mean),
+            p95_ns: nanos(record.stats.p95),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<StoredRecord> for Record {
+    type Error = String;
+
+    fn try_from(stored: StoredRecord) -> Result<Self, Self::Error> {
+        let puzzle = PuzzleId::try_new(stored.year, stored.day)
+            .ok_or_else(|| format!("{} has no day {}", stored.year, stored.day))?;
+
+        Ok(Record {
+            revision: stored.revision,
+            puzzle,
+            part: stored.part,
+            stats: Stats {
+                min: Duration::from_nanos(stored.min_ns),
+                median: Duration::from_nanos(stored.median_ns),
+                mean: Duration::from_nanos(stored.mean_ns),
+                p95: Duration::from_nanos(stored.p95_ns),
+            },
+        })
+    }
+}
+
+/// An error which can be returned when reading or writing a baseline file.
+#[expect(clippy::module_name_repetitions, reason = "Name clarity")]
+#[derive(Debug)]
+pub enum BaselineError {
+    Io(PathBuf, io::Error),
+    #[cfg(feature = "serde")]
+    Malformed(PathBuf, serde_json::Error),
+    /// Built without the `serde` feature.
+    #[cfg(not(feature = "serde"))]
+    Unsupported(PathBuf),
+}
+
+impl Error for BaselineError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            BaselineError::Io(_, ref error) => Some(error),
+            #[cfg(feature = "serde")]
+            BaselineError::Malformed(_, ref error) => Some(error),
+            #[cfg(not(feature = "serde"))]
+            BaselineError::Unsupported(_) => None,
+        }
+    }
+}
+
+impl Display for BaselineError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            BaselineError::Io(ref path, ref error) => {
+                write!(f, "error accessing \"{}\": {}", path.display(), error)
+            },
+            #[cfg(feature = "serde")]
+            BaselineError::Malformed(ref path, ref error) => {
+                write!(
+                    f,
+                    "\"{}\" is not a baseline file: {}",
+                    path.display(),
+                    error
+                )
+            },
+            #[cfg(not(feature = "serde"))]
+            BaselineError::Unsupported(ref path) => {
+                write!(
+                    f,
+                    "cannot use \"{}\": baselines need the `serde` feature",
+                    path.display()
+                )
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    #[cfg(feature = "serde")]
+    use std::{env, fs, process};
+
+    #[cfg(feature = "serde")]
+    use super::{Record, load, save};
+    use super::{Regression, merge, regressions};
+    use crate::puzzle;
+    use crate::runner::bench::{Benchmark, Stats};
+    use crate::shared::part::Part;
+
+    fn stats(median: u64) -> Stats {
+        Stats {
+            min: Duration::from_millis(median - 1),
+            median: Duration::from_millis(median),
+            mean: Duration::from_millis(median),
+            p95: Duration::from_millis(median + 1),
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn round_trip() {
+        let records = vec![
+            Record {
+                revision: "abc1234".into(),
+                puzzle: puzzle!(2024, 4),
+                part: Part::One,
+                stats: stats(10),
+            },
+            Record {
+                revision: "abc1234-dirty".into(),
+                puzzle: puzzle!(2025, 12),
+                part: Part::Two,
+                stats: stats(300),
+            },
+        ];
+
+        let path = env::temp_dir().join(format!("aoc-{}-baselines.json", process::id()));
+
+        save(&path, &records).unwrap();
+        let loaded = load(&path);
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(records, loaded.unwrap());
+        assert_eq!(Vec::<Record>::new(), load(&path).unwrap());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn stored() {
+        let record = Record {
+            revision: "abc1234".into(),
+            puzzle: puzzle!(2024, 4),
+            part: Part::One,
+            stats: stats(10),
+        };
+
+        assert_eq!(
+            r#"{"revision":"abc1234","year":2024,"day":4,"part":1,"min_ns":9000000,"median_ns":10000000,"mean_ns":10000000,"p95_ns":11000000}"#,
+            serde_json::to_string(&record).unwrap()
+        );
+
+        // files saved before there were several years only have this crate's year
+        assert_eq!(
+            record,
+            serde_json::from_str(
+                r#"{"revision":"abc1234","day":4,"part":1,"min_ns":9000000,"median_ns":10000000,"mean_ns":10000000,"p95_ns":11000000}"#
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn malformed() {
+        let error = serde_json::from_str::<Vec<Record>>(
+            "[\n  {\"revision\": \"abc1234\", \"day\": 4, \"part\": 1}\n]",
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            "missing field `min_ns` at line 2 column 46",
+            error.to_string()
+        );
+
+        let error = serde_json::from_str::<Vec<Record>>(
+            r#"[{"revision":"abc1234","year":2025,"day":13,"part":1,"min_ns":1,"median_ns":1,"mean_ns":1,"p95_ns":1}]"#,
+        )
+        .unwrap_err();
+
+        assert_eq!("2025 has no day 13 at line 1 column 102", error.to_string());
+    }
+
+    #[test]
+    fn merge_replaces() {
+        let mut records = vec![];
+
+        let benchmark = |median| Benchmark {
+            puzzle: puzzle!(2024, 6),
+            part: Part::Two,
+            stats: stats(median),
+        };
+
+        merge(&mut records, "old", &[benchmark(10)]);
+        merge(&mut records, "new", &[benchmark(20)]);
+        merge(&mut records, "new", &[benchmark(30)]);
+
+        assert_eq!(
+            vec![("old", 10), ("new", 30)],
+            records
+                .iter()
+                .map(|record| (record.revision.as_str(), record.stats.median.as_millis()))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn threshold() {
+        let mut records = vec![];
+
+        merge(
+            &mut records,
+            "old",
+            &[
+                Benchmark {
+                    puzzle: puzzle!(2024, 10),
+                    part: Part::One,
+                    stats: stats(100),
+                },
+                Benchmark {
+                    puzzle: puzzle!(2024, 12),
+                    part: Part::One,
+                    stats: stats(100),
+                },
+            ],
+        );
+
+        let current = [
+            Benchmark {
+                puzzle: puzzle!(2024, 10),
+                part: Part::One,
+                stats: stats(110),
+            },
+            Benchmark {
+                puzzle: puzzle!(2024, 12),
+                part: Part::One,
+                stats: stats(111),
+            },
+            Benchmark {
+                puzzle: puzzle!(2024, 16),
+                part: Part::One,
+                stats: stats(500),
+            },
+        ];
+
+        assert_eq!(
+            vec![Regression {
+                puzzle: puzzle!(2024, 12),
+                part: Part::One,
+                baseline: Duration::from_millis(100),
+                current: Duration::from_millis(111),
+            }],
+            regressions(&records, "old", &current, 10)
+        );
+    }
+}
@@ -0,0 +1,196 @@
+use std::time::{Duration, Instant};
+
+use super::solver::Solver;
+use crate::shared::error::SolveError;
+use crate::shared::params::Param;
+use crate::shared::part::Part;
+use crate::shared::puzzle::PuzzleId;
+
+/// How many times each part is run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Options {
+    /// The runs that are measured.
+    pub runs: usize,
+    /// The runs before that, which are not measured.
+    pub warmup: usize,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            runs: 10,
+            warmup: 1,
+        }
+    }
+}
+
+/// A summary of a set of timings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    /// Summarizes `samples`, sorting them in the process.
+    ///
+    /// Returns `None` when there are no samples.
+    ///
+    /// # Panics
+    /// When there are more than `u32::MAX` samples
+    #[must_use]
+    pub fn from_samples(samples: &mut [Duration]) -> Option<Stats> {
+        samples.sort_unstable();
+
+        let count = samples.len();
+
+        let &min = samples.first()?;
+
+        let median = if count.is_multiple_of(2) {
+            (samples[count / 2 - 1] + samples[count / 2]) / 2
+        } else {
+            samples[count / 2]
+        };
+
+        let mean = samples.iter().sum::<Duration>()
+            / u32::try_from(count).expect("Fewer than 2^32 samples");
+
+        // nearest-rank
+        let p95 = samples[(count * 95).div_ceil(100) - 1];
+
+        Some(Stats {
+            min,
+            median,
+            mean,
+            p95,
+        })
+    }
+}
+
+/// The timings of one part of one puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Benchmark {
+    pub puzzle: PuzzleId,
+    pub part: Part,
+    pub stats: Stats,
+}
+
+/// The timings of parsing a day's input, and of solving each of the requested parts.
+pub struct DayBenchmark {
+    pub parse: Stats,
+    pub parts: Vec<Benchmark>,
+}
+
+/// Parses `input` and solves `parts` with it and `params`, `options.warmup` times without
+/// measuring and `options.runs` times while measuring.
+///
+/// # Errors
+/// When a parameter cannot be overridden, the input is malformed, or a part cannot be solved. The
+/// part is included in the error.
+pub fn bench(
+    puzzle: PuzzleId,
+    solution: &dyn Solver,
+    input: &str,
+    parts: &[Part],
+    params: &[Param],
+    options: Options,
+) -> Result<Option<DayBenchmark>, (Option<Part>, SolveError)> {
+    let mut parse_samples = Vec::with_capacity(options.runs);
+    let mut part_samples = vec![Vec::with_capacity(options.runs); parts.len()];
+
+    for run in 0..options.warmup + options.runs {
+        let mut failure = None;
+
+        let parse_elapsed = solution
+            .parse_then(input, params, &mut |parsed| {
+                for (&part, samples) in parts.iter().zip(&mut part_samples) {
+                    let start = Instant::now();
+
+                    let answer = parsed.solve(part);
+
+                    let elapsed = start.elapsed();
+
+                    if let Err(error) = answer {
+                        failure = Some((part, error));
+
+                        return;
+                    }
+
+                    if run >= options.warmup {
+                        samples.push(elapsed);
+                    }
+                }
+            })
+            .map_err(|error| (None, error))?;
+
+        if let Some((part, error)) = failure {
+            return Err((Some(part), error));
+        }
+
+        if run >= options.warmup {
+            parse_samples.push(parse_elapsed);
+        }
+    }
+
+    let Some(parse) = Stats::from_samples(&mut parse_samples) else {
+        return Ok(None);
+    };
+
+    let parts = parts
+        .iter()
+        .zip(&mut part_samples)
+        .filter_map(|(&part, samples)| {
+            Some(Benchmark {
+                puzzle,
+                part,
+                stats: Stats::from_samples(samples)?,
+            })
+        })
+        .collect();
+
+    Ok(Some(DayBenchmark { parse, parts }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::Stats;
+
+    fn millis(millis: &[u64]) -> Vec<Duration> {
+        millis.iter().copied().map(Duration::from_millis).collect()
+    }
+
+    #[test]
+    fn odd() {
+        assert_eq!(
+            Some(Stats {
+                min: Duration::from_millis(1),
+                median: Duration::from_millis(3),
+                mean: Duration::from_millis(4),
+                p95: Duration::from_millis(10),
+            }),
+            Stats::from_samples(&mut millis(&[10, 1, 3, 4, 2]))
+        );
+    }
+
+    #[test]
+    fn even() {
+        let stats = Stats::from_samples(&mut millis(&[
+            20, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19,
+        ]))
+        .unwrap();
+
+        assert_eq!(Duration::from_millis(1), stats.min, "Min");
+        assert_eq!(Duration::from_micros(10_500), stats.median, "Median");
+        assert_eq!(Duration::from_micros(10_500), stats.mean, "Mean");
+        assert_eq!(Duration::from_millis(19), stats.p95, "P95");
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(None, Stats::from_samples(&mut []));
+    }
+}
@@ -0,0 +1,607 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write as _};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::shared::answers::{ANSWERS_FILE, EXAMPLES_FILE};
+use crate::shared::puzzle::PuzzleId;
+use crate::shared::year::Year;
+
+/// The kind of solution a new day starts from.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Template {
+    /// Both parts on the input as it is.
+    #[default]
+    Plain,
+    /// The input parsed into a [`Grid`](crate::shared::grids::grid::Grid) of characters.
+    Grid,
+    /// The input parsed into the edges between nodes, from lines like `a-b`.
+    Graph,
+}
+
+impl Template {
+    fn source(self) -> &'static str {
+        match self {
+            Template::Plain => include_str!("../template/plain.rs"),
+            Template::Grid => include_str!("../template/grid.rs"),
+            Template::Graph => include_str!("../template/graph.rs"),
+        }
+    }
+
+    /// The solution, titled `title` when there is one.
+    fn render(self, title: Option<&str>) -> String {
+        match title {
+            Some(title) => self.source().replace(
+                "\"TITLE\"",
+                &format!("\"{}\"", title.replace('\\', "\\\\").replace('"', "\\\"")),
+            ),
+            None => self
+                .source()
+                .replace("title: \"TITLE\", ", "")
+                .replace("title: \"TITLE\"", ""),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Template {
+    type Err = TemplateFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "plain" => Ok(Template::Plain),
+            "grid" => Ok(Template::Grid),
+            "graph" => Ok(Template::Graph),
+            _ => Err(TemplateFromStrError {}),
+        }
+    }
+}
+
+/// An error which can be returned when parsing a [`Template`].
+#[derive(Debug)]
+pub struct TemplateFromStrError {}
+
+impl Error for TemplateFromStrError {}
+
+impl Display for TemplateFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting `plain`, `grid` or `graph`")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// What `aoc new` creates.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewDay {
+    pub puzzle: PuzzleId,
+    pub template: Template,
+    pub title: Option<String>,
+    /// The number of example files, `NN.txt` for one and `NN-1.txt` and on for more.
+    pub examples: u8,
+}
+
+/// Creates the solution of a new day in the crate at `root` and registers it in `src/days.rs`,
+/// along with its binary, and empty input and example files with placeholder answers in
+/// `data_dir`.
+///
+/// Days of other events than [`Year::DEFAULT`] go in a module named after their year, like
+/// `src/days/y2025/day_01.rs`, with a binary named like `2025-01`.
+///
+/// Nothing is written when the solution or its binary already exists. Data files that exist are
+/// kept as they are.
+///
+/// Returns every file that was created or changed.
+///
+/// # Errors
+/// When the day already exists, a file cannot be written, or `src/days.rs` doesn't look like a
+/// registry
+pub fn scaffold(
+    root: &Path,
+    data_dir: &Path,
+    new_day: &NewDay,
+) -> Result<Vec<PathBuf>, ScaffoldError> {
+    let puzzle = new_day.puzzle;
+    let day = puzzle.day();
+    let days_dir = root.join("src/days");
+
+    let year_module = (puzzle.year() != Year::DEFAULT).then(|| format!("y{}", puzzle.year()));
+
+    let (solution, module, binary) = match year_module {
+        Some(ref year_module) => (
+            days_dir.join(year_module).join(format!("day_{}.rs", day)),
+            format!("{}::day_{}", year_module, day),
+            format!("{}-{}.rs", puzzle.year(), day),
+        ),
+        None => (
+            days_dir.join(format!("day_{}.rs", day)),
+            format!("day_{}", day),
+            format!("{}.rs", day),
+        ),
+    };
+
+    let binary = root.join("src/bin").join(binary);
+    let registry_path = root.join("src/days.rs");
+
+    for path in [&solution, &binary] {
+        if path.exists() {
+            return Err(ScaffoldError::Exists(path.clone()));
+        }
+    }
+
+    let registry = read(&registry_path)?;
+
+    let registry = register(&registry, puzzle, &module)
+        .ok_or_else(|| ScaffoldError::Unregistrable(registry_path.clone()))?;
+
+    let year_registry = match year_module {
+        Some(ref year_module) => {
+            let path = days_dir.join(format!("{}.rs", year_module));
+
+            let contents = if path.exists() {
+                read(&path)?
+            } else {
+                String::new()
+            };
+
+            let mut lines = contents.lines().map(ToOwned::to_owned).collect::<Vec<_>>();
+
+            if add_module(&mut lines, &format!("day_{}", day)).is_none() {
+                lines.push(format!("pub mod day_{};", day));
+            }
+
+            Some((path, lines))
+        },
+        None => None,
+    };
+
+    write(
+        &solution,
+        &new_day.template.render(new_day.title.as_deref()),
+    )?;
+    write(
+        &binary,
+        &format!(
+            "use std::process::ExitCode;\n\nuse {}::days::{};\n\nfn main() -> ExitCode {{\n    day_{}::main()\n}}\n",
+            env!("CARGO_CRATE_NAME"),
+            module,
+            day
+        ),
+    )?;
+    write(&registry_path, &registry)?;
+
+    let mut changed = vec![solution, binary, registry_path];
+
+    if let Some((path, lines)) = year_registry {
+        let mut contents = lines.join("\n");
+        contents.push('\n');
+
+        write(&path, &contents)?;
+
+        changed.push(path);
+    }
+
+    let year_dir = data_dir.join(puzzle.year().to_string());
+
+    let examples = match new_day.examples {
+        0 => vec![],
+        1 => vec![day.to_string()],
+        count => (1..=count)
+            .map(|variant| format!("{}-{}", day, variant))
+            .collect(),
+    };
+
+    for name in examples.iter().map(|example| format!("{}.txt", example)) {
+        let path = year_dir.join("examples").join(name);
+
+        if create(&path)? {
+            changed.push(path);
+        }
+    }
+
+    let input = year_dir.join("inputs").join(format!("{}.txt", day));
+
+    if create(&input)? {
+        changed.push(input);
+    }
+
+    for (file, keys) in [
+        (ANSWERS_FILE, vec![day.to_string()]),
+        (EXAMPLES_FILE, examples),
+    ] {
+        let path = year_dir.join(file);
+
+        if add_placeholders(&path, &keys)? {
+            changed.push(path);
+        }
+    }
+
+    Ok(changed)
+}
+
+/// `registry` with the module at `path` added to the modules and `puzzle` to `SOLUTIONS`, both
+/// kept in order. `None` when `registry` doesn't have either, or already has the puzzle.
+fn register(registry: &str, puzzle: PuzzleId, path: &str) -> Option<String> {
+    let entry = format!(
+        "    (puzzle!({}, {}), &{}::Solution {{}}, &{}::METADATA),",
+        puzzle.year(),
+        puzzle.day().into_inner(),
+        path,
+        path
+    );
+
+    let mut lines = registry.lines().map(ToOwned::to_owned).collect::<Vec<_>>();
+
+    if lines.iter().any(|line| entry_puzzle(line) == Some(puzzle)) {
+        return None;
+    }
+
+    add_module(&mut lines, path.split("::").next()?)?;
+
+    let header = lines
+        .iter()
+        .position(|line| line.starts_with("const SOLUTIONS: ["))?;
+
+    let (start, end) = lines[header].rsplit_once("; ")?;
+    let count = end.strip_suffix("] = [")?.parse::<usize>().ok()?;
+
+    lines[header] = format!("{}; {}] = [", start, count + 1);
+
+    let entries_end = header
+        + 1
+        + lines[header + 1..]
+            .iter()
+            .take_while(|line| line.starts_with("    (puzzle!("))
+            .count();
+
+    let entry_at = (header + 1..entries_end)
+        .find(|&index| entry_puzzle(&lines[index]).is_some_and(|other| other > puzzle))
+        .unwrap_or(entries_end);
+
+    lines.insert(entry_at, entry);
+
+    let mut registry = lines.join("\n");
+    registry.push('\n');
+
+    Some(registry)
+}
+
+/// Adds `pub mod module;` to the modules in `lines`, in order, unless it's there already. `None`
+/// when `lines` has no modules.
+fn add_module(lines: &mut Vec<String>, module: &str) -> Option<()> {
+    let module_line = format!("pub mod {};", module);
+
+    if lines.contains(&module_line) {
+        return Some(());
+    }
+
+    let modules = lines.iter().position(|line| line.starts_with("pub mod "))?;
+    let modules_end = modules
+        + lines[modules..]
+            .iter()
+            .take_while(|line| line.starts_with("pub mod "))
+            .count();
+
+    let module_at = (modules..modules_end)
+        .find(|&index| lines[index] > module_line)
+        .unwrap_or(modules_end);
+
+    lines.insert(module_at, module_line);
+
+    Some(())
+}
+
+/// The puzzle of a line of `SOLUTIONS`, like `    (puzzle!(2024, 7), ...`.
+fn entry_puzzle(line: &str) -> Option<PuzzleId> {
+    let (puzzle, _) = line.strip_prefix("    (puzzle!(")?.split_once(')')?;
+    let (year, day) = puzzle.split_once(", ")?;
+
+    PuzzleId::try_new(year.parse().ok()?, day.parse().ok()?)
+}
+
+/// Appends an empty table for every key in `keys` that the answers file at `path` doesn't have
+/// yet. Returns whether the file was changed.
+fn add_placeholders(path: &Path, keys: &[String]) -> Result<bool, ScaffoldError> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(error) => return Err(ScaffoldError::Io(path.to_owned(), error)),
+    };
+
+    let mut placeholders = String::new();
+
+    for key in keys {
+        let table = format!("[{}]", key);
+
+        if !contents.lines().any(|line| line.trim() == table) {
+            placeholders.push('\n');
+            placeholders.push_str(&table);
+            placeholders.push_str("\n# part_1 =\n# part_2 =\n");
+        }
+    }
+
+    if placeholders.is_empty() {
+        return Ok(false);
+    }
+
+    append(path, &placeholders)?;
+
+    Ok(true)
+}
+
+fn read(path: &Path) -> Result<String, ScaffoldError> {
+    fs::read_to_string(path).map_err(|error| ScaffoldError::Io(path.to_owned(), error))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), ScaffoldError> {
+    let io = |error| ScaffoldError::Io(path.to_owned(), error);
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(io)?;
+    }
+
+    fs::write(path, contents).map_err(io)
+}
+
+fn append(path: &Path, contents: &str) -> Result<(), ScaffoldError> {
+    let io = |error| ScaffoldError::Io(path.to_owned(), error);
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(io)?;
+    }
+
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(contents.as_bytes()))
+        .map_err(io)
+}
+
+/// Creates an empty file at `path`, unless there is one. Returns whether it was created.
+fn create(path: &Path) -> Result<bool, ScaffoldError> {
+    if path.exists() {
+        return Ok(false);
+    }
+
+    append(path, "")?;
+
+    Ok(true)
+}
+
+/// An error which can be returned when creating a new day.
+#[expect(clippy::module_name_repetitions, reason = "Name clarity")]
+#[derive(Debug)]
+pub enum ScaffoldError {
+    Exists(PathBuf),
+    Unregistrable(PathBuf),
+    Io(PathBuf, io::Error),
+}
+
+impl Error for ScaffoldError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            ScaffoldError::Io(_, ref error) => Some(error),
+            ScaffoldError::Exists(_) | ScaffoldError::Unregistrable(_) => None,
+        }
+    }
+}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            ScaffoldError::Exists(ref path) => {
+                write!(
+                    f,
+                    "\"{}\" already exists, not overwriting it",
+                    path.display()
+                )
+            },
+            ScaffoldError::Unregistrable(ref path) => write!(
+                f,
+                "cannot register the day in \"{}\", it has the day already or no `SOLUTIONS`",
+                path.display()
+            ),
+            ScaffoldError::Io(ref path, ref error) => {
+                write!(f, "error accessing \"{}\": {}", path.display(), error)
+            },
+        }
+    }
+}
+
+/// The templates as solutions of their own, so they keep up with the API they're written against.
+#[cfg(test)]
+#[path = "../template"]
+#[expect(
+    dead_code,
+    reason = "`main` and `PUZZLE` only make sense in the day the template starts"
+)]
+mod templates {
+    pub mod graph;
+    pub mod grid;
+    pub mod plain;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+    use std::{env, fs, process};
+
+    use super::{NewDay, ScaffoldError, Template, scaffold, templates};
+    use crate::puzzle;
+    use crate::shared::tag::Tag;
+    use crate::shared::{PartSolution, TryParts as _};
+
+    const REGISTRY: &str = "\
+pub mod day_01;
+pub mod day_05;
+
+use crate::puzzle;
+
+const SOLUTIONS: [(PuzzleId, &dyn Solver, &Metadata); 2] = [
+    (puzzle!(2024, 1), &day_01::Solution {}, &day_01::METADATA),
+    (puzzle!(2024, 5), &day_05::Solution {}, &day_05::METADATA),
+];
+";
+
+    /// A crate root of its own in the temporary directory, removed again when dropped, so a
+    /// failed test doesn't leave it behind for the next run.
+    struct TempRoot(PathBuf);
+
+    impl TempRoot {
+        fn new(name: &str) -> Self {
+            let path = env::temp_dir().join(format!("aoc-{}-{}", process::id(), name));
+
+            // left behind by a run that was killed before it could clean up
+            if path.exists() {
+                fs::remove_dir_all(&path).unwrap();
+            }
+
+            TempRoot(path)
+        }
+    }
+
+    impl Drop for TempRoot {
+        fn drop(&mut self) {
+            // panicking here would abort a test that is already failing
+            if let Err(error) = fs::remove_dir_all(&self.0) {
+                eprintln!("cannot remove \"{}\": {}", self.0.display(), error);
+            }
+        }
+    }
+
+    #[test]
+    fn new_day() {
+        let temp = TempRoot::new("scaffold");
+        let root = &temp.0;
+        let data_dir = root.join("data");
+
+        fs::create_dir_all(root.join("src/days")).unwrap();
+        fs::create_dir_all(root.join("src/bin")).unwrap();
+        fs::write(root.join("src/days.rs"), REGISTRY).unwrap();
+        fs::create_dir_all(data_dir.join("2024/examples")).unwrap();
+        fs::write(
+            data_dir.join("2024/examples/answers.toml"),
+            "[01]\npart_1 = 1\n",
+        )
+        .unwrap();
+
+        let new_day = NewDay {
+            puzzle: puzzle!(2024, 3),
+            template: Template::Grid,
+            title: Some("Say \"Hi\"".into()),
+            examples: 2,
+        };
+
+        let paths = scaffold(root, &data_dir, &new_day).unwrap();
+        let read = |path: &str| fs::read_to_string(root.join(path)).unwrap();
+
+        assert_eq!(8, paths.len());
+        assert_eq!(
+            "\
+pub mod day_01;
+pub mod day_03;
+pub mod day_05;
+
+use crate::puzzle;
+
+const SOLUTIONS: [(PuzzleId, &dyn Solver, &Metadata); 3] = [
+    (puzzle!(2024, 1), &day_01::Solution {}, &day_01::METADATA),
+    (puzzle!(2024, 3), &day_03::Solution {}, &day_03::METADATA),
+    (puzzle!(2024, 5), &day_05::Solution {}, &day_05::METADATA),
+];
+",
+            read("src/days.rs")
+        );
+        assert!(read("src/days/day_03.rs").contains(r#"title: "Say \"Hi\"", tags: [Grids]"#));
+        assert!(read("src/bin/03.rs").contains("day_03::main()"));
+        assert!(Path::new(&data_dir.join("2024/inputs/03.txt")).exists());
+        assert!(Path::new(&data_dir.join("2024/examples/03-2.txt")).exists());
+        assert_eq!(
+            "[01]\npart_1 = 1\n\n[03-1]\n# part_1 =\n# part_2 =\n\n[03-2]\n# part_1 =\n# part_2 =\n",
+            read("data/2024/examples/answers.toml")
+        );
+
+        assert!(matches!(
+            scaffold(root, &data_dir, &new_day),
+            Err(ScaffoldError::Exists(_))
+        ));
+    }
+
+    #[test]
+    fn new_year() {
+        let temp = TempRoot::new("scaffold-year");
+        let root = &temp.0;
+        let data_dir = root.join("data");
+
+        fs::create_dir_all(root.join("src/days")).unwrap();
+        fs::write(root.join("src/days.rs"), REGISTRY).unwrap();
+
+        let new_day = |puzzle| NewDay {
+            puzzle,
+            template: Template::Plain,
+            title: None,
+            examples: 1,
+        };
+
+        scaffold(root, &data_dir, &new_day(puzzle!(2025, 7))).unwrap();
+        scaffold(root, &data_dir, &new_day(puzzle!(2025, 2))).unwrap();
+
+        let read = |path: &str| fs::read_to_string(root.join(path)).unwrap();
+
+        assert_eq!(
+            "\
+pub mod day_01;
+pub mod day_05;
+pub mod y2025;
+
+use crate::puzzle;
+
+const SOLUTIONS: [(PuzzleId, &dyn Solver, &Metadata); 4] = [
+    (puzzle!(2024, 1), &day_01::Solution {}, &day_01::METADATA),
+    (puzzle!(2024, 5), &day_05::Solution {}, &day_05::METADATA),
+    (puzzle!(2025, 2), &y2025::day_02::Solution {}, &y2025::day_02::METADATA),
+    (puzzle!(2025, 7), &y2025::day_07::Solution {}, &y2025::day_07::METADATA),
+];
+",
+            read("src/days.rs")
+        );
+        assert_eq!(
+            "pub mod day_02;\npub mod day_07;\n",
+            read("src/days/y2025.rs")
+        );
+        assert!(Path::new(&root.join("src/days/y2025/day_07.rs")).exists());
+        assert!(read("src/bin/2025-02.rs").contains("days::y2025::day_02;"));
+        assert!(Path::new(&data_dir.join("2025/inputs/07.txt")).exists());
+    }
+
+    #[test]
+    fn templates() {
+        assert_eq!(Some("TITLE"), templates::plain::METADATA.title);
+        assert_eq!(&[Tag::Grids], templates::grid::METADATA.tags);
+        assert_eq!(&[Tag::Graphs], templates::graph::METADATA.tags);
+
+        for part in [
+            templates::plain::Solution {}.try_part_1("1\n"),
+            templates::grid::Solution {}.try_part_2("#.\n.#\n"),
+            templates::graph::Solution {}.try_part_1("a-b\n"),
+        ] {
+            assert!(matches!(part, Ok(PartSolution::None)));
+        }
+    }
+
+    #[test]
+    fn untitled() {
+        assert_eq!(
+            Some("crate::solution!(tags: [Graphs]);"),
+            Template::Graph
+                .render(None)
+                .lines()
+                .find(|line| line.starts_with("crate::solution!"))
+        );
+        assert!(Template::Plain.render(None).contains("crate::solution!();"));
+    }
+}
@@ -0,0 +1,71 @@
+use std::time::{Duration, Instant};
+
+use crate::shared::error::SolveError;
+use crate::shared::params::{Param, Params as _};
+use crate::shared::part::Part;
+use crate::shared::{PartSolution, TryParts};
+
+/// An input that has been parsed by a solution, ready to be solved.
+pub trait ParsedInput {
+    /// # Errors
+    /// When the parsed input cannot be solved
+    fn solve(&self, part: Part) -> Result<PartSolution, SolveError>;
+}
+
+/// The object safe face of [`TryParts`], so solutions with different parsed inputs can share a
+/// registry.
+pub trait Solver {
+    /// Parses `input` and hands the result to `solve`, which solves with the default parameters
+    /// overridden by `params`. Returns how long parsing took.
+    ///
+    /// # Errors
+    /// When a parameter cannot be overridden or the input is malformed, in which case `solve` is
+    /// not called
+    fn parse_then(
+        &self,
+        input: &str,
+        params: &[Param],
+        solve: &mut dyn FnMut(&dyn ParsedInput),
+    ) -> Result<Duration, SolveError>;
+}
+
+struct ParsedBy<'solution, 'parsed, 'input, S: TryParts> {
+    solution: &'solution S,
+    parsed: &'parsed S::Parsed<'input>,
+    params: S::Params,
+}
+
+impl<S: TryParts> ParsedInput for ParsedBy<'_, '_, '_, S> {
+    fn solve(&self, part: Part) -> Result<PartSolution, SolveError> {
+        match part {
+            Part::One => self.solution.try_part_1_parsed(self.parsed, &self.params),
+            Part::Two => self.solution.try_part_2_parsed(self.parsed, &self.params),
+        }
+    }
+}
+
+impl<S: TryParts> Solver for S {
+    fn parse_then(
+        &self,
+        input: &str,
+        params: &[Param],
+        solve: &mut dyn FnMut(&dyn ParsedInput),
+    ) -> Result<Duration, SolveError> {
+        let params =
+            S::Params::with(params).map_err(|error| SolveError::Invalid(error.to_string()))?;
+
+        let start = Instant::now();
+
+        let parsed = self.parse(input)?;
+
+        let elapsed = start.elapsed();
+
+        solve(&ParsedBy {
+            solution: self,
+            parsed: &parsed,
+            params,
+        });
+
+        Ok(elapsed)
+    }
+}
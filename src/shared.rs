@@ -1,15 +1,108 @@
-use std::cmp::Ordering;
-
+use error::SolveError;
+use grids::grid::Grid;
+use params::Params;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+pub mod answers;
+mod comparison;
 pub mod day;
+pub mod day_set;
+pub mod error;
 pub mod grids;
+pub mod metadata;
+#[cfg(feature = "ocr")]
+pub mod ocr;
+pub mod params;
+pub mod part;
+pub mod puzzle;
 pub mod solution;
+pub mod tag;
 pub mod tree;
+pub mod year;
 
 pub trait Parts {
     fn part_1(&self, input: &str) -> PartSolution;
     fn part_2(&self, input: &str) -> PartSolution;
 }
 
+/// Like [`Parts`], but for solutions that report malformed input instead of panicking, and that
+/// parse their input once for both parts.
+///
+/// Every [`Parts`] is a [`TryParts`] that never fails, without parameters, and whose parsed input
+/// is the input itself.
+pub trait TryParts {
+    /// The input after parsing, shared by both parts.
+    type Parsed<'input>;
+
+    /// The parameters of the puzzle that aren't part of the input, `()` when there are none.
+    type Params: Params;
+
+    /// # Errors
+    /// When the input is malformed
+    fn parse<'input>(&self, input: &'input str) -> Result<Self::Parsed<'input>, SolveError>;
+
+    /// # Errors
+    /// When the parsed input cannot be solved
+    fn try_part_1_parsed(
+        &self,
+        parsed: &Self::Parsed<'_>,
+        params: &Self::Params,
+    ) -> Result<PartSolution, SolveError>;
+
+    /// # Errors
+    /// When the parsed input cannot be solved
+    fn try_part_2_parsed(
+        &self,
+        parsed: &Self::Parsed<'_>,
+        params: &Self::Params,
+    ) -> Result<PartSolution, SolveError>;
+
+    /// Parses `input` and solves part 1 with the default parameters.
+    ///
+    /// # Errors
+    /// When the input is malformed or cannot be solved
+    fn try_part_1(&self, input: &str) -> Result<PartSolution, SolveError> {
+        self.try_part_1_parsed(&self.parse(input)?, &Self::Params::default())
+    }
+
+    /// Parses `input` and solves part 2 with the default parameters.
+    ///
+    /// # Errors
+    /// When the input is malformed or cannot be solved
+    fn try_part_2(&self, input: &str) -> Result<PartSolution, SolveError> {
+        self.try_part_2_parsed(&self.parse(input)?, &Self::Params::default())
+    }
+}
+
+impl<T: Parts + ?Sized> TryParts for T {
+    type Parsed<'input> = &'input str;
+
+    type Params = ();
+
+    fn parse<'input>(&self, input: &'input str) -> Result<Self::Parsed<'input>, SolveError> {
+        Ok(input)
+    }
+
+    fn try_part_1_parsed(
+        &self,
+        parsed: &Self::Parsed<'_>,
+        _params: &Self::Params,
+    ) -> Result<PartSolution, SolveError> {
+        Ok(self.part_1(parsed))
+    }
+
+    fn try_part_2_parsed(
+        &self,
+        parsed: &Self::Parsed<'_>,
+        _params: &Self::Params,
+    ) -> Result<PartSolution, SolveError> {
+        Ok(self.part_2(parsed))
+    }
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum PartSolution {
     I32(i32),
     U32(u32),
@@ -17,9 +110,21 @@ pub enum PartSolution {
     U64(u64),
     ISize(isize),
     USize(usize),
+    I128(i128),
+    U128(u128),
     String(String),
     Vec(Vec<String>),
-    Manual,
+    /// A position, written as `x,y`.
+    Coordinates(i64, i64),
+    /// A list written on one line, like a password.
+    Joined {
+        items: Vec<String>,
+        separator: String,
+    },
+    /// A picture, written one row per line.
+    Grid(Grid<char>),
+    /// A picture that has to be read to get the answer, usually a few letters.
+    Manual(Grid<char>),
     None,
 }
 
@@ -32,9 +137,17 @@ impl std::fmt::Debug for PartSolution {
             Self::U64(arg0) => write!(f, "{}u64", arg0),
             Self::ISize(arg0) => write!(f, "{}isize", arg0),
             Self::USize(arg0) => write!(f, "{}usize", arg0),
+            Self::I128(arg0) => write!(f, "{}i128", arg0),
+            Self::U128(arg0) => write!(f, "{}u128", arg0),
             Self::String(ref arg0) => write!(f, "\"{}\"", arg0),
             Self::Vec(ref arg0) => write!(f, "{:?}", arg0),
-            Self::Manual => write!(f, "Manual"),
+            Self::Coordinates(x, y) => write!(f, "({}, {})", x, y),
+            Self::Joined {
+                ref items,
+                ref separator,
+            } => write!(f, "{:?} joined by {:?}", items, separator),
+            Self::Grid(ref arg0) => write!(f, "{:?}", arg0),
+            Self::Manual(ref arg0) => write!(f, "Manual {:?}", arg0),
             Self::None => write!(f, "None"),
         }
     }
@@ -45,21 +158,39 @@ impl PartSolution {
     pub fn has_solution(&self) -> bool {
         !matches!(*self, PartSolution::None)
     }
-}
 
-impl PartialEq<PartSolution> for PartSolution {
-    fn eq(&self, other: &PartSolution) -> bool {
+    /// The letters drawn in a [`PartSolution::Manual`] picture, when they can be recognised.
+    /// Needs the `ocr` feature, without it nothing is recognised.
+    #[must_use]
+    pub fn read(&self) -> Option<String> {
         match *self {
-            PartSolution::I32(ref i) => i == other,
-            PartSolution::U32(ref i) => i == other,
-            PartSolution::I64(ref i) => i == other,
-            PartSolution::U64(ref i) => i == other,
-            PartSolution::ISize(ref i) => i == other,
-            PartSolution::USize(ref i) => i == other,
-            PartSolution::String(ref i) => i == other,
-            PartSolution::Vec(ref i) => i == other,
-            PartSolution::None => matches!(other, &PartSolution::None),
-            PartSolution::Manual => matches!(other, &PartSolution::Manual),
+            #[cfg(feature = "ocr")]
+            PartSolution::Manual(ref grid) => ocr::recognise(grid),
+            #[cfg(not(feature = "ocr"))]
+            PartSolution::Manual(_) => None,
+            PartSolution::I32(_)
+            | PartSolution::U32(_)
+            | PartSolution::I64(_)
+            | PartSolution::U64(_)
+            | PartSolution::ISize(_)
+            | PartSolution::USize(_)
+            | PartSolution::I128(_)
+            | PartSolution::U128(_)
+            | PartSolution::String(_)
+            | PartSolution::Vec(_)
+            | PartSolution::Coordinates(..)
+            | PartSolution::Joined { .. }
+            | PartSolution::Grid(_)
+            | PartSolution::None => None,
+        }
+    }
+
+    /// A list written on one line, with `separator` between the items.
+    #[must_use]
+    pub fn joined(items: Vec<String>, separator: &str) -> Self {
+        PartSolution::Joined {
+            items,
+            separator: separator.to_owned(),
         }
     }
 }
@@ -100,6 +231,18 @@ impl From<usize> for PartSolution {
     }
 }
 
+impl From<i128> for PartSolution {
+    fn from(v: i128) -> Self {
+        PartSolution::I128(v)
+    }
+}
+
+impl From<u128> for PartSolution {
+    fn from(v: u128) -> Self {
+        PartSolution::U128(v)
+    }
+}
+
 impl From<Vec<String>> for PartSolution {
     fn from(v: Vec<String>) -> Self {
         PartSolution::Vec(v)
@@ -118,6 +261,12 @@ impl From<&'_ str> for PartSolution {
     }
 }
 
+impl From<Grid<char>> for PartSolution {
+    fn from(v: Grid<char>) -> Self {
+        PartSolution::Grid(v)
+    }
+}
+
 impl From<Option<PartSolution>> for PartSolution {
     fn from(value: Option<PartSolution>) -> Self {
         match value {
@@ -136,346 +285,21 @@ impl std::fmt::Display for PartSolution {
             PartSolution::U64(other) => other.to_string(),
             PartSolution::ISize(other) => other.to_string(),
             PartSolution::USize(other) => other.to_string(),
+            PartSolution::I128(other) => other.to_string(),
+            PartSolution::U128(other) => other.to_string(),
             PartSolution::String(ref other) => other.to_owned(),
             PartSolution::Vec(ref other) => format!("\n{}", other.join("\n")),
-            PartSolution::Manual => "Manual".to_owned(),
+            PartSolution::Coordinates(x, y) => format!("{},{}", x, y),
+            PartSolution::Joined {
+                ref items,
+                ref separator,
+            } => items.join(separator),
+            PartSolution::Grid(ref grid) | PartSolution::Manual(ref grid) => {
+                format!("\n{}", grid.lines().join("\n"))
+            },
             PartSolution::None => "None".to_owned(),
         };
 
         write!(f, "{}", string)
     }
 }
-
-impl std::cmp::PartialEq<PartSolution> for i32 {
-    fn eq(&self, other: &PartSolution) -> bool {
-        match *other {
-            PartSolution::I32(other) => *self == other,
-            PartSolution::U32(other) => i64::from(*self) == i64::from(other),
-            PartSolution::I64(other) => i64::from(*self) == other,
-            PartSolution::U64(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::ISize(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::USize(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::String(_)
-            | PartSolution::Vec(_)
-            | PartSolution::Manual
-            | PartSolution::None => false,
-        }
-    }
-}
-
-impl std::cmp::PartialEq<PartSolution> for u32 {
-    fn eq(&self, other: &PartSolution) -> bool {
-        match *other {
-            PartSolution::I32(other) => i64::from(*self) == i64::from(other),
-            PartSolution::U32(other) => *self == other,
-            PartSolution::I64(other) => i64::from(*self) == other,
-            PartSolution::U64(other) => u64::from(*self) == other,
-            PartSolution::ISize(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::USize(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::String(_)
-            | PartSolution::Vec(_)
-            | PartSolution::Manual
-            | PartSolution::None => false,
-        }
-    }
-}
-
-impl std::cmp::PartialEq<PartSolution> for i64 {
-    fn eq(&self, other: &PartSolution) -> bool {
-        match *other {
-            PartSolution::I32(other) => *self == Self::from(other),
-            PartSolution::U32(other) => *self == Self::from(other),
-            PartSolution::I64(other) => *self == other,
-            PartSolution::U64(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::ISize(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::USize(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::String(_)
-            | PartSolution::Vec(_)
-            | PartSolution::Manual
-            | PartSolution::None => false,
-        }
-    }
-}
-
-impl std::cmp::PartialEq<PartSolution> for u64 {
-    fn eq(&self, other: &PartSolution) -> bool {
-        match *other {
-            PartSolution::I32(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::U32(other) => *self == Self::from(other),
-            PartSolution::I64(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::U64(other) => *self == other,
-            PartSolution::ISize(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::USize(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::String(_)
-            | PartSolution::Vec(_)
-            | PartSolution::Manual
-            | PartSolution::None => false,
-        }
-    }
-}
-
-impl std::cmp::PartialEq<PartSolution> for isize {
-    fn eq(&self, other: &PartSolution) -> bool {
-        match *other {
-            PartSolution::I32(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::U32(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::I64(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::U64(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::ISize(other) => *self == other,
-            PartSolution::USize(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::String(_)
-            | PartSolution::Vec(_)
-            | PartSolution::Manual
-            | PartSolution::None => false,
-        }
-    }
-}
-
-impl std::cmp::PartialEq<PartSolution> for usize {
-    fn eq(&self, other: &PartSolution) -> bool {
-        match *other {
-            PartSolution::I32(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::U32(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::I64(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::U64(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::ISize(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::USize(other) => *self == other,
-            PartSolution::String(_)
-            | PartSolution::Vec(_)
-            | PartSolution::Manual
-            | PartSolution::None => false,
-        }
-    }
-}
-
-impl std::cmp::PartialEq<PartSolution> for String {
-    fn eq(&self, other: &PartSolution) -> bool {
-        match *other {
-            PartSolution::String(ref s) => s == self,
-            PartSolution::I32(_)
-            | PartSolution::U32(_)
-            | PartSolution::I64(_)
-            | PartSolution::U64(_)
-            | PartSolution::ISize(_)
-            | PartSolution::USize(_)
-            | PartSolution::Vec(_)
-            | PartSolution::Manual
-            | PartSolution::None => false,
-        }
-    }
-}
-
-impl std::cmp::PartialEq<PartSolution> for Vec<String> {
-    fn eq(&self, other: &PartSolution) -> bool {
-        matches!(*other, PartSolution::Vec(ref v) if {
-            if v.len() != self.len() {
-                return false;
-            }
-
-            for (l, r) in self.iter().zip(v) {
-                if l != r {
-                    return false;
-                }
-            }
-
-            true
-        })
-    }
-}
-
-impl std::cmp::PartialOrd<PartSolution> for i32 {
-    fn partial_cmp(&self, other: &PartSolution) -> Option<Ordering> {
-        match *other {
-            PartSolution::I32(ref other) => self.partial_cmp(other),
-            PartSolution::U32(other) => i64::from(*self).partial_cmp(&i64::from(other)),
-            PartSolution::I64(ref other) => i64::from(*self).partial_cmp(other),
-            PartSolution::U64(other) => {
-                if let Ok(other) = Self::try_from(other) {
-                    self.partial_cmp(&other)
-                } else {
-                    // other doesn't fit into the smaller i32, meaning self is Less
-                    Some(Ordering::Less)
-                }
-            },
-            PartSolution::USize(other) => {
-                if let Ok(other) = Self::try_from(other) {
-                    self.partial_cmp(&other)
-                } else {
-                    // other doesn't fit into the smaller i32, meaning self is Less
-                    Some(Ordering::Less)
-                }
-            },
-            PartSolution::ISize(_)
-            | PartSolution::String(_)
-            | PartSolution::Vec(_)
-            | PartSolution::Manual
-            | PartSolution::None => None,
-        }
-    }
-}
-
-impl std::cmp::PartialOrd<PartSolution> for u32 {
-    fn partial_cmp(&self, other: &PartSolution) -> Option<Ordering> {
-        match *other {
-            PartSolution::I32(other) => {
-                if let Ok(other) = Self::try_from(other) {
-                    self.partial_cmp(&other)
-                } else {
-                    // other is negative, so we are by definition Greater
-                    Some(Ordering::Greater)
-                }
-            },
-            PartSolution::U32(ref other) => self.partial_cmp(other),
-            PartSolution::I64(ref other) => i64::from(*self).partial_cmp(other),
-            PartSolution::U64(ref other) => u64::from(*self).partial_cmp(other),
-            PartSolution::USize(other) => {
-                if let Ok(other) = Self::try_from(other) {
-                    self.partial_cmp(&other)
-                } else {
-                    // other doesn't fit into the smaller u32, meaning self is Less
-                    Some(Ordering::Less)
-                }
-            },
-            PartSolution::ISize(_)
-            | PartSolution::String(_)
-            | PartSolution::Vec(_)
-            | PartSolution::Manual
-            | PartSolution::None => None,
-        }
-    }
-}
-
-impl std::cmp::PartialOrd<PartSolution> for u64 {
-    fn partial_cmp(&self, other: &PartSolution) -> Option<Ordering> {
-        match *other {
-            PartSolution::I32(other) => {
-                if let Ok(other) = Self::try_from(other) {
-                    self.partial_cmp(&other)
-                } else {
-                    // other is negative, so we are by definition Greater
-                    Some(Ordering::Greater)
-                }
-            },
-            PartSolution::U32(other) => self.partial_cmp(&u64::from(other)),
-            PartSolution::I64(other) => {
-                if let Ok(other) = Self::try_from(other) {
-                    self.partial_cmp(&other)
-                } else {
-                    // other is negative, so we are by definition Greater
-                    Some(Ordering::Greater)
-                }
-            },
-            PartSolution::U64(ref other) => self.partial_cmp(other),
-            PartSolution::USize(other) => {
-                if let Ok(other) = Self::try_from(other) {
-                    self.partial_cmp(&other)
-                } else {
-                    // other doesn't fit into usize, meaning self is Less
-                    Some(Ordering::Less)
-                }
-            },
-            PartSolution::ISize(_)
-            | PartSolution::String(_)
-            | PartSolution::Vec(_)
-            | PartSolution::Manual
-            | PartSolution::None => None,
-        }
-    }
-}
-
-impl std::cmp::PartialOrd<PartSolution> for usize {
-    fn partial_cmp(&self, other: &PartSolution) -> Option<Ordering> {
-        match *other {
-            PartSolution::I32(other) => {
-                if other.is_negative() {
-                    Some(Ordering::Greater)
-                } else if let Ok(other) = Self::try_from(other) {
-                    self.partial_cmp(&other)
-                } else {
-                    // other is positive, but doesn't fit into usize, so we're Less
-                    Some(Ordering::Less)
-                }
-            },
-            PartSolution::U32(other) => {
-                // if this fails, that means that usize is smaller than u32
-                if let Ok(other) = Self::try_from(other) {
-                    self.partial_cmp(&other)
-                } else {
-                    // other doesn't fit into usize
-                    Some(Ordering::Less)
-                }
-            },
-            PartSolution::I64(other) => {
-                if other.is_negative() {
-                    Some(Ordering::Greater)
-                } else if let Ok(other) = Self::try_from(other) {
-                    self.partial_cmp(&other)
-                } else {
-                    // other is positive, but doesn't fit into usize, so we're Less
-                    Some(Ordering::Less)
-                }
-            },
-            PartSolution::U64(other) => {
-                // if this fails, that means that usize is smaller than u64
-                if let Ok(other) = Self::try_from(other) {
-                    self.partial_cmp(&other)
-                } else {
-                    // other doesn't fit into usize
-                    Some(Ordering::Less)
-                }
-            },
-            PartSolution::USize(other) => other.partial_cmp(self),
-            PartSolution::ISize(_)
-            | PartSolution::String(_)
-            | PartSolution::Vec(_)
-            | PartSolution::Manual
-            | PartSolution::None => None,
-        }
-    }
-}
-
-impl std::cmp::PartialOrd<PartSolution> for String {
-    fn partial_cmp(&self, other: &PartSolution) -> Option<Ordering> {
-        match *other {
-            PartSolution::String(ref s) => s.partial_cmp(self),
-            PartSolution::I32(_)
-            | PartSolution::U32(_)
-            | PartSolution::I64(_)
-            | PartSolution::U64(_)
-            | PartSolution::ISize(_)
-            | PartSolution::USize(_)
-            | PartSolution::Vec(_)
-            | PartSolution::Manual
-            | PartSolution::None => None,
-        }
-    }
-}
-
-impl std::cmp::PartialOrd<PartSolution> for Vec<String> {
-    fn partial_cmp(&self, other: &PartSolution) -> Option<Ordering> {
-        match *other {
-            PartSolution::Vec(ref v) => {
-                if v.len() != self.len() {
-                    return None;
-                }
-
-                for (l, r) in self.iter().zip(v) {
-                    if l != r {
-                        return None;
-                    }
-                }
-
-                Some(Ordering::Equal)
-            },
-            PartSolution::I32(_)
-            | PartSolution::U32(_)
-            | PartSolution::I64(_)
-            | PartSolution::U64(_)
-            | PartSolution::ISize(_)
-            | PartSolution::USize(_)
-            | PartSolution::String(_)
-            | PartSolution::Manual
-            | PartSolution::None => None,
-        }
-    }
-}
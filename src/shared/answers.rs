@@ -0,0 +1,793 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::error::Error;
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::str::{Chars, FromStr};
+use std::{fs, io};
+
+use super::PartSolution;
+use super::day::Day;
+use super::error::SolveError;
+use super::params::Param;
+use super::part::Part;
+use super::solution::data_dirs;
+use super::year::Year;
+
+/// The name of the answers file in the directory of a year.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// The name of the expected example answers file in the directory of a year.
+pub const EXAMPLES_FILE: &str = "examples/answers.toml";
+
+/// What a table of an answers file is keyed by.
+pub trait AnswerKey: Copy + Ord + Display + FromStr {
+    /// The answers file, relative to the directory of a year in the data directory.
+    const FILE: &'static str;
+
+    /// The comment at the top of the answers file.
+    const HEADER: &'static str;
+
+    /// The error when a table key cannot be parsed.
+    const EXPECTED: &'static str;
+}
+
+impl AnswerKey for Day {
+    const FILE: &'static str = ANSWERS_FILE;
+
+    const HEADER: &'static str = "\
+# The known answers, keyed by day and part.
+#
+# The day binaries, `aoc run` and the integration tests check the solutions against these.
+# `--record` adds answers that are missing, rewriting this file.
+";
+
+    const EXPECTED: &'static str = "expected a day between 1 and 25";
+}
+
+/// An example input of a day, e.g. `10-2` for `examples/10-2.txt` and `03` for `examples/03.txt`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Example {
+    pub day: Day,
+    pub variant: Option<u8>,
+}
+
+impl AnswerKey for Example {
+    const FILE: &'static str = EXAMPLES_FILE;
+
+    const HEADER: &'static str = "\
+# The expected answers to the examples, keyed by the example's file name and part.
+#
+# The integration tests run every example with an answer here, with the parameters given by
+# `params`, e.g. `params = [\"width=11\", \"height=7\"]`.
+";
+
+    const EXPECTED: &'static str =
+        "expected a day between 1 and 25, optionally followed by `-` and a number";
+}
+
+impl Display for Example {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.variant {
+            Some(variant) => write!(f, "{}-{}", self.day, variant),
+            None => write!(f, "{}", self.day),
+        }
+    }
+}
+
+impl FromStr for Example {
+    type Err = ExampleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (day, variant) = match s.split_once('-') {
+            Some((day, variant)) => (day, Some(variant.parse().map_err(|_| ExampleError)?)),
+            None => (s, None),
+        };
+
+        Ok(Example {
+            day: day.parse().map_err(|_| ExampleError)?,
+            variant,
+        })
+    }
+}
+
+/// An error which can be returned when parsing an [`Example`].
+#[derive(Debug)]
+pub struct ExampleError;
+
+impl Error for ExampleError {}
+
+impl Display for ExampleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("invalid example")
+    }
+}
+
+/// The known answers of every day, as stored in [`ANSWERS_FILE`], or of every example, as stored
+/// in [`EXAMPLES_FILE`].
+///
+/// The file is a small subset of TOML, one table per day or example, with integers, strings and
+/// arrays of strings as answers, and optionally the parameters the answers are for:
+///
+/// ```toml
+/// [17]
+/// part_1 = "3,4,3,1,7,6,5,6,0"
+/// part_2 = 109019930331546
+///
+/// [18]
+/// params = ["size=7", "bytes=12"]
+/// part_1 = 22
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct Answers<K = Day> {
+    answers: BTreeMap<(K, Part), PartSolution>,
+    params: BTreeMap<K, Vec<Param>>,
+}
+
+impl<K> Default for Answers<K> {
+    fn default() -> Self {
+        Self {
+            answers: BTreeMap::new(),
+            params: BTreeMap::new(),
+        }
+    }
+}
+
+impl<K: AnswerKey> Answers<K> {
+    /// The answer to `part` of `key`, if it is known.
+    #[must_use]
+    pub fn get(&self, key: K, part: Part) -> Option<&PartSolution> {
+        self.answers.get(&(key, part))
+    }
+
+    /// Sets the answer to `part` of `key`. A [`PartSolution::Manual`] picture is recorded as the
+    /// text it [reads](PartSolution::read) as. [`PartSolution::None`] and pictures that cannot be
+    /// read aren't answers, and forget the known answer instead.
+    pub fn set(&mut self, key: K, part: Part, answer: PartSolution) {
+        let answer = match answer {
+            PartSolution::Manual(_) => answer
+                .read()
+                .map_or(PartSolution::None, PartSolution::String),
+            PartSolution::I32(_)
+            | PartSolution::U32(_)
+            | PartSolution::I64(_)
+            | PartSolution::U64(_)
+            | PartSolution::ISize(_)
+            | PartSolution::USize(_)
+            | PartSolution::I128(_)
+            | PartSolution::U128(_)
+            | PartSolution::String(_)
+            | PartSolution::Vec(_)
+            | PartSolution::Coordinates(..)
+            | PartSolution::Joined { .. }
+            | PartSolution::Grid(_)
+            | PartSolution::None => answer,
+        };
+
+        if answer.has_solution() {
+            self.answers.insert((key, part), answer);
+        } else {
+            self.answers.remove(&(key, part));
+        }
+    }
+
+    /// The parameters the answers of `key` are for, empty for the defaults.
+    #[must_use]
+    pub fn params(&self, key: K) -> &[Param] {
+        self.params.get(&key).map_or(&[], Vec::as_slice)
+    }
+
+    /// Sets the parameters the answers of `key` are for.
+    pub fn set_params(&mut self, key: K, params: Vec<Param>) {
+        if params.is_empty() {
+            self.params.remove(&key);
+        } else {
+            self.params.insert(key, params);
+        }
+    }
+
+    /// Every known answer, in order.
+    pub fn iter(&self) -> impl Iterator<Item = (K, Part, &PartSolution)> {
+        self.answers
+            .iter()
+            .map(|(&(key, part), answer)| (key, part, answer))
+    }
+
+    /// The path of the answers file of `year`: the first one found in the data directories, or a
+    /// new one in the first data directory.
+    #[must_use]
+    pub fn path(year: Year) -> PathBuf {
+        let file = Path::new(&year.to_string()).join(K::FILE);
+
+        let data_dirs = data_dirs();
+
+        data_dirs
+            .iter()
+            .map(|data_dir| data_dir.join(&file))
+            .find(|path| path.is_file())
+            .or_else(|| data_dirs.first().map(|data_dir| data_dir.join(&file)))
+            .unwrap_or_else(|| Path::new("data").join(&file))
+    }
+
+    /// Reads the answers file at `path`. A file that doesn't exist has no answers.
+    ///
+    /// # Errors
+    /// When the file cannot be read or is malformed
+    pub fn load(path: &Path) -> Result<Answers<K>, AnswersError> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Answers::default()),
+            Err(error) => return Err(AnswersError::Io(path.to_owned(), error)),
+        };
+
+        contents
+            .parse()
+            .map_err(|error| AnswersError::Malformed(path.to_owned(), error))
+    }
+
+    /// Writes the answers to `path`, replacing what was there.
+    ///
+    /// The file is written from scratch, starting with the header of its kind, so comments and
+    /// formatting added by hand are lost.
+    ///
+    /// # Errors
+    /// When the file cannot be written
+    pub fn save(&self, path: &Path) -> Result<(), AnswersError> {
+        fs::write(path, self.to_string()).map_err(|error| AnswersError::Io(path.to_owned(), error))
+    }
+}
+
+impl<K: AnswerKey> Display for Answers<K> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(K::HEADER)?;
+
+        let keys = self
+            .answers
+            .keys()
+            .map(|&(key, _)| key)
+            .chain(self.params.keys().copied())
+            .collect::<BTreeSet<_>>();
+
+        for key in keys {
+            write!(f, "\n[{}]\n", key)?;
+
+            if let Some(params) = self.params.get(&key) {
+                f.write_str("params = ")?;
+
+                write_strings(
+                    f,
+                    &params.iter().map(ToString::to_string).collect::<Vec<_>>(),
+                )?;
+
+                writeln!(f)?;
+            }
+
+            for part in Part::ALL {
+                let Some(answer) = self.answers.get(&(key, part)) else {
+                    continue;
+                };
+
+                write!(f, "part_{} = ", part)?;
+
+                match *answer {
+                    PartSolution::String(ref string) => write_string(f, string)?,
+                    PartSolution::Vec(ref strings) => write_strings(f, strings)?,
+                    PartSolution::Grid(ref grid) => write_strings(f, &grid.lines())?,
+                    PartSolution::Coordinates(..) | PartSolution::Joined { .. } => {
+                        write_string(f, &answer.to_string())?;
+                    },
+                    PartSolution::I32(_)
+                    | PartSolution::U32(_)
+                    | PartSolution::I64(_)
+                    | PartSolution::U64(_)
+                    | PartSolution::ISize(_)
+                    | PartSolution::USize(_)
+                    | PartSolution::I128(_)
+                    | PartSolution::U128(_)
+                    | PartSolution::None => write!(f, "{}", answer)?,
+                    PartSolution::Manual(_) => unreachable!("Pictures are recorded as text"),
+                }
+
+                writeln!(f)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn write_strings(f: &mut std::fmt::Formatter<'_>, strings: &[String]) -> std::fmt::Result {
+    f.write_str("[")?;
+
+    for (index, string) in strings.iter().enumerate() {
+        if index > 0 {
+            f.write_str(", ")?;
+        }
+
+        write_string(f, string)?;
+    }
+
+    f.write_str("]")
+}
+
+fn write_string(f: &mut std::fmt::Formatter<'_>, string: &str) -> std::fmt::Result {
+    f.write_str("\"")?;
+
+    for c in string.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\t' => f.write_str("\\t")?,
+            _ => write!(f, "{}", c)?,
+        }
+    }
+
+    f.write_str("\"")
+}
+
+/// Walks over a single line, keeping track of the 1-based column.
+struct Cursor<'line> {
+    chars: Chars<'line>,
+    line: usize,
+    column: usize,
+}
+
+impl Cursor<'_> {
+    fn error<M: Into<String>>(&self, message: M) -> SolveError {
+        SolveError::parse(self.line, self.column, message)
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.clone().next()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let next = self.chars.next();
+
+        if next.is_some() {
+            self.column += 1;
+        }
+
+        next
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(|c| c == ' ' || c == '\t') {
+            self.next();
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), SolveError> {
+        self.skip_whitespace();
+
+        if self.peek() == Some(expected) {
+            self.next();
+
+            Ok(())
+        } else {
+            Err(self.error(format!("expected `{}`", expected)))
+        }
+    }
+
+    /// Whether only whitespace and a comment are left.
+    fn at_end(&mut self) -> bool {
+        self.skip_whitespace();
+
+        self.peek().is_none_or(|c| c == '#')
+    }
+
+    fn string(&mut self) -> Result<String, SolveError> {
+        self.expect('"')?;
+
+        let mut string = String::new();
+
+        loop {
+            match self.next() {
+                Some('"') => return Ok(string),
+                Some('\\') => match self.next() {
+                    Some('"') => string.push('"'),
+                    Some('\\') => string.push('\\'),
+                    Some('n') => string.push('\n'),
+                    Some('t') => string.push('\t'),
+                    _ => return Err(self.error("unsupported escape")),
+                },
+                Some(c) => string.push(c),
+                None => return Err(self.error("unterminated string")),
+            }
+        }
+    }
+
+    fn integer(&mut self) -> Result<PartSolution, SolveError> {
+        let start = self.column;
+
+        let mut digits = String::new();
+
+        while let Some(c) = self
+            .peek()
+            .filter(|&c| c.is_ascii_digit() || c == '_' || (digits.is_empty() && c == '-'))
+        {
+            self.next();
+
+            if c != '_' {
+                digits.push(c);
+            }
+        }
+
+        let invalid = || SolveError::parse(self.line, start, "expected an answer");
+
+        // the smallest type that fits, so answers beyond 64 bits don't need to be strings
+        if digits.starts_with('-') {
+            digits
+                .parse::<i64>()
+                .map(PartSolution::I64)
+                .or_else(|_| digits.parse::<i128>().map(PartSolution::I128))
+                .map_err(|_| invalid())
+        } else {
+            digits
+                .parse::<u64>()
+                .map(PartSolution::U64)
+                .or_else(|_| digits.parse::<u128>().map(PartSolution::U128))
+                .map_err(|_| invalid())
+        }
+    }
+
+    fn value(&mut self) -> Result<PartSolution, SolveError> {
+        self.skip_whitespace();
+
+        match self.peek() {
+            Some('"') => Ok(PartSolution::String(self.string()?)),
+            Some('[') => {
+                self.next();
+
+                let mut strings = vec![];
+
+                self.skip_whitespace();
+
+                if self.peek() == Some(']') {
+                    self.next();
+
+                    return Ok(PartSolution::Vec(strings));
+                }
+
+                loop {
+                    self.skip_whitespace();
+
+                    strings.push(self.string()?);
+
+                    self.skip_whitespace();
+
+                    match self.next() {
+                        Some(',') => {},
+                        Some(']') => return Ok(PartSolution::Vec(strings)),
+                        _ => return Err(self.error("expected `,` or `]`")),
+                    }
+                }
+            },
+            _ => self.integer(),
+        }
+    }
+}
+
+impl<K: AnswerKey> FromStr for Answers<K> {
+    type Err = SolveError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut answers = Answers::default();
+
+        let mut table = None;
+
+        for (line_index, line) in s.lines().enumerate() {
+            let mut cursor = Cursor {
+                chars: line.chars(),
+                line: line_index + 1,
+                column: 1,
+            };
+
+            if cursor.at_end() {
+                continue;
+            }
+
+            if cursor.peek() == Some('[') {
+                cursor.next();
+                cursor.skip_whitespace();
+
+                let start = cursor.column;
+
+                let key = if cursor.peek() == Some('"') {
+                    cursor.string()?
+                } else {
+                    let mut key = String::new();
+
+                    while let Some(c) = cursor.peek().filter(|&c| c.is_ascii_digit() || c == '-') {
+                        cursor.next();
+
+                        key.push(c);
+                    }
+
+                    key
+                };
+
+                table = Some(
+                    key.parse::<K>()
+                        .map_err(|_| SolveError::parse(cursor.line, start, K::EXPECTED))?,
+                );
+
+                cursor.expect(']')?;
+            } else {
+                let start = cursor.column;
+
+                let mut key = String::new();
+
+                while let Some(c) = cursor
+                    .peek()
+                    .filter(|&c| c.is_ascii_alphanumeric() || c == '_')
+                {
+                    cursor.next();
+
+                    key.push(c);
+                }
+
+                // `None` for the parameters
+                let part = match key.as_str() {
+                    "part_1" => Some(Part::One),
+                    "part_2" => Some(Part::Two),
+                    "params" => None,
+                    _ => {
+                        return Err(SolveError::parse(
+                            cursor.line,
+                            start,
+                            "expected `part_1`, `part_2` or `params`",
+                        ));
+                    },
+                };
+
+                let Some(table) = table else {
+                    return Err(SolveError::parse(
+                        cursor.line,
+                        start,
+                        "expected a `[day]` before the answers",
+                    ));
+                };
+
+                cursor.expect('=')?;
+                cursor.skip_whitespace();
+
+                let start = cursor.column;
+
+                let value = cursor.value()?;
+
+                if let Some(part) = part {
+                    answers.set(table, part, value);
+                } else {
+                    let invalid = || {
+                        SolveError::parse(
+                            cursor.line,
+                            start,
+                            "expected an array of `NAME=VALUE` strings",
+                        )
+                    };
+
+                    let PartSolution::Vec(params) = value else {
+                        return Err(invalid());
+                    };
+
+                    let params = params
+                        .iter()
+                        .map(|param| param.parse())
+                        .collect::<Result<_, _>>()
+                        .map_err(|_| invalid())?;
+
+                    answers.set_params(table, params);
+                }
+            }
+
+            if !cursor.at_end() {
+                return Err(cursor.error("expected the end of the line"));
+            }
+        }
+
+        Ok(answers)
+    }
+}
+
+/// An error which can be returned when reading or writing the answers file.
+#[expect(clippy::module_name_repetitions, reason = "Name clarity")]
+#[derive(Debug)]
+pub enum AnswersError {
+    Io(PathBuf, io::Error),
+    Malformed(PathBuf, SolveError),
+}
+
+impl Error for AnswersError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            AnswersError::Io(_, ref error) => Some(error),
+            AnswersError::Malformed(_, ref error) => Some(error),
+        }
+    }
+}
+
+impl Display for AnswersError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            AnswersError::Io(ref path, ref error) => {
+                write!(f, "error accessing \"{}\": {}", path.display(), error)
+            },
+            AnswersError::Malformed(ref path, ref error) => {
+                write!(f, "\"{}\" is malformed: {}", path.display(), error)
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Answers, Example};
+    use crate::day;
+    use crate::shared::PartSolution;
+    use crate::shared::error::SolveError;
+    use crate::shared::grids::grid::Grid;
+    use crate::shared::params::Param;
+    use crate::shared::part::Part;
+
+    #[test]
+    fn parse() {
+        let answers = "\
+# comment
+[07]
+part_1 = 4_555_081_946_288 # trailing comment
+
+[\"17\"]
+part_1 = \"3,4,3,1,7,6,5,6,0\"
+part_2 = -12
+[24]
+part_2 = [\"a\", \"b\\\"c\"]
+[25]
+part_1 = 18_446_744_073_709_551_616
+part_2 = -9_223_372_036_854_775_809
+"
+        .parse::<Answers>()
+        .unwrap();
+
+        assert_eq!(
+            Some(&PartSolution::U64(4_555_081_946_288)),
+            answers.get(day!(7), Part::One)
+        );
+        assert_eq!(None, answers.get(day!(7), Part::Two));
+        assert_eq!(
+            Some(&PartSolution::String("3,4,3,1,7,6,5,6,0".into())),
+            answers.get(day!(17), Part::One)
+        );
+        assert_eq!(
+            Some(&PartSolution::I64(-12)),
+            answers.get(day!(17), Part::Two)
+        );
+        assert_eq!(
+            Some(&PartSolution::Vec(vec!["a".into(), "b\"c".into()])),
+            answers.get(day!(24), Part::Two)
+        );
+        assert_eq!(
+            Some(&PartSolution::U128(u128::from(u64::MAX) + 1)),
+            answers.get(day!(25), Part::One)
+        );
+        assert_eq!(
+            Some(&PartSolution::I128(i128::from(i64::MIN) - 1)),
+            answers.get(day!(25), Part::Two)
+        );
+    }
+
+    #[test]
+    fn examples() {
+        let answers = "[10-2]\npart_1 = 4\n\n[03]\nparams = [\"size=7\"]\npart_2 = 48\n"
+            .parse::<Answers<Example>>()
+            .unwrap();
+
+        let example = Example {
+            day: day!(10),
+            variant: Some(2),
+        };
+
+        assert_eq!(Some(&PartSolution::U64(4)), answers.get(example, Part::One));
+        assert_eq!(
+            Some(&PartSolution::U64(48)),
+            answers.get(
+                Example {
+                    day: day!(3),
+                    variant: None
+                },
+                Part::Two
+            )
+        );
+        assert_eq!(
+            &["size=7".parse::<Param>().unwrap()],
+            answers.params(Example {
+                day: day!(3),
+                variant: None
+            })
+        );
+        assert!(answers.params(example).is_empty(), "Unexpected params");
+        assert!(
+            answers
+                .to_string()
+                .ends_with("\n[03]\nparams = [\"size=7\"]\npart_2 = 48\n\n[10-2]\npart_1 = 4\n"),
+            "Unexpected output: {}",
+            answers
+        );
+        assert_eq!(
+            Err(SolveError::parse(1, 2, "expected a day between 1 and 25")),
+            "[10-2]".parse::<Answers>()
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut answers = Answers::default();
+
+        answers.set(day!(1), Part::One, 1_579_939_u32.into());
+        answers.set(day!(1), Part::Two, 20_351_745_usize.into());
+        answers.set(
+            day!(14),
+            Part::Two,
+            Grid::new(vec![vec!['#', '.'], vec!['.', '#']]).into(),
+        );
+        answers.set(day!(18), Part::Two, PartSolution::Coordinates(6, 1));
+        answers.set(
+            day!(23),
+            Part::Two,
+            PartSolution::joined(vec!["as".into(), "bu".into(), "cp".into()], ","),
+        );
+        answers.set(day!(25), Part::Two, PartSolution::None);
+
+        let written = answers.to_string();
+
+        assert!(
+            written.ends_with(
+                "\n[01]\npart_1 = 1579939\npart_2 = 20351745\n\n[14]\npart_2 = [\"#.\", \".#\"]\n\n[18]\npart_2 = \"6,1\"\n\n[23]\npart_2 = \"as,bu,cp\"\n"
+            ),
+            "Unexpected output: {}",
+            written
+        );
+
+        assert_eq!(Ok(answers), written.parse());
+    }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(
+            Err(SolveError::parse(
+                1,
+                1,
+                "expected a `[day]` before the answers"
+            )),
+            "part_1 = 1".parse::<Answers>()
+        );
+        assert_eq!(
+            Err(SolveError::parse(1, 2, "expected a day between 1 and 25")),
+            "[26]".parse::<Answers>()
+        );
+        assert_eq!(
+            Err(SolveError::parse(
+                2,
+                1,
+                "expected `part_1`, `part_2` or `params`"
+            )),
+            "[01]\npart_3 = 1".parse::<Answers>()
+        );
+        assert_eq!(
+            Err(SolveError::parse(
+                2,
+                10,
+                "expected an array of `NAME=VALUE` strings"
+            )),
+            "[01]\nparams = [\"size\"]".parse::<Answers>()
+        );
+        assert_eq!(
+            Err(SolveError::parse(2, 10, "expected an answer")),
+            "[01]\npart_1 = one".parse::<Answers>()
+        );
+        assert_eq!(
+            Err(SolveError::parse(2, 12, "expected the end of the line")),
+            "[01]\npart_1 = 1 2".parse::<Answers>()
+        );
+    }
+}
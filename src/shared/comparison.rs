@@ -0,0 +1,533 @@
+use std::cmp::Ordering;
+
+use super::PartSolution;
+
+/// An integer of any width and signedness, ordered by its value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Integer {
+    // declared first, so every negative integer is less than every non-negative one
+    Negative(i128),
+    NonNegative(u128),
+}
+
+/// The integer types of the [`PartSolution`] variants.
+trait Widen: Copy {
+    fn widen(self) -> Integer;
+}
+
+/// Implements [`Widen`] for each integer type, and compares it with every [`PartSolution`]: equal
+/// to and ordered against the integer variants by value, regardless of their type, and neither
+/// equal to nor ordered against the others.
+macro_rules! integers {
+    ($($type:ty),*) => {$(
+        impl Widen for $type {
+            fn widen(self) -> Integer {
+                match u128::try_from(self) {
+                    Ok(value) => Integer::NonNegative(value),
+                    // only signed integers are negative, and those all fit in an i128
+                    Err(_) => Integer::Negative(
+                        i128::try_from(self).expect("Signed integers fit in an i128"),
+                    ),
+                }
+            }
+        }
+
+        impl PartialEq<PartSolution> for $type {
+            fn eq(&self, other: &PartSolution) -> bool {
+                integer(other) == Some(self.widen())
+            }
+        }
+
+        impl PartialOrd<PartSolution> for $type {
+            fn partial_cmp(&self, other: &PartSolution) -> Option<Ordering> {
+                Some(self.widen().cmp(&integer(other)?))
+            }
+        }
+    )*};
+}
+
+integers!(i32, u32, i64, u64, isize, usize, i128, u128);
+
+/// The value of an integer variant, `None` for the other variants.
+fn integer(solution: &PartSolution) -> Option<Integer> {
+    match *solution {
+        PartSolution::I32(value) => Some(value.widen()),
+        PartSolution::U32(value) => Some(value.widen()),
+        PartSolution::I64(value) => Some(value.widen()),
+        PartSolution::U64(value) => Some(value.widen()),
+        PartSolution::ISize(value) => Some(value.widen()),
+        PartSolution::USize(value) => Some(value.widen()),
+        PartSolution::I128(value) => Some(value.widen()),
+        PartSolution::U128(value) => Some(value.widen()),
+        PartSolution::String(_)
+        | PartSolution::Vec(_)
+        | PartSolution::Coordinates(..)
+        | PartSolution::Joined { .. }
+        | PartSolution::Grid(_)
+        | PartSolution::Manual(_)
+        | PartSolution::None => None,
+    }
+}
+
+/// The structured variants are equal to the same variant by value, and to the way they are
+/// written in the answers file: coordinates and joined lists to a string, and a grid to its rows.
+/// They are only ordered against the same variant, except for grids which have no order.
+impl PartialEq<PartSolution> for PartSolution {
+    fn eq(&self, other: &PartSolution) -> bool {
+        match *self {
+            PartSolution::String(ref string) => string == other,
+            PartSolution::Vec(ref strings) => strings == other,
+            PartSolution::Coordinates(x, y) => {
+                if let PartSolution::Coordinates(other_x, other_y) = *other {
+                    (x, y) == (other_x, other_y)
+                } else {
+                    matches!(*other, PartSolution::String(ref string) if *string == *self)
+                }
+            },
+            PartSolution::Joined {
+                ref items,
+                ref separator,
+            } => {
+                if let PartSolution::Joined {
+                    items: ref other_items,
+                    separator: ref other_separator,
+                } = *other
+                {
+                    (items, separator) == (other_items, other_separator)
+                } else {
+                    matches!(*other, PartSolution::String(ref string) if *string == *self)
+                }
+            },
+            PartSolution::Grid(ref grid) => {
+                if let PartSolution::Grid(ref other_grid) = *other {
+                    grid == other_grid
+                } else {
+                    matches!(*other, PartSolution::Vec(ref strings) if *strings == *self)
+                }
+            },
+            PartSolution::Manual(ref grid) => {
+                if let PartSolution::Manual(ref other_grid) = *other {
+                    grid == other_grid
+                } else {
+                    matches!(*other, PartSolution::String(ref string) if *string == *self)
+                }
+            },
+            PartSolution::None => matches!(*other, PartSolution::None),
+            PartSolution::I32(_)
+            | PartSolution::U32(_)
+            | PartSolution::I64(_)
+            | PartSolution::U64(_)
+            | PartSolution::ISize(_)
+            | PartSolution::USize(_)
+            | PartSolution::I128(_)
+            | PartSolution::U128(_) => integer(self) == integer(other),
+        }
+    }
+}
+
+impl PartialOrd<PartSolution> for PartSolution {
+    fn partial_cmp(&self, other: &PartSolution) -> Option<Ordering> {
+        match *self {
+            PartSolution::String(ref string) => string.partial_cmp(other),
+            PartSolution::Vec(ref strings) => strings.partial_cmp(other),
+            PartSolution::Coordinates(x, y) => {
+                if let PartSolution::Coordinates(other_x, other_y) = *other {
+                    Some((x, y).cmp(&(other_x, other_y)))
+                } else {
+                    (self == other).then_some(Ordering::Equal)
+                }
+            },
+            PartSolution::Joined {
+                ref items,
+                ref separator,
+            } => match *other {
+                PartSolution::Joined {
+                    items: ref other_items,
+                    separator: ref other_separator,
+                } if separator == other_separator => Some(items.cmp(other_items)),
+                PartSolution::I32(_)
+                | PartSolution::U32(_)
+                | PartSolution::I64(_)
+                | PartSolution::U64(_)
+                | PartSolution::ISize(_)
+                | PartSolution::USize(_)
+                | PartSolution::I128(_)
+                | PartSolution::U128(_)
+                | PartSolution::String(_)
+                | PartSolution::Vec(_)
+                | PartSolution::Coordinates(..)
+                | PartSolution::Joined { .. }
+                | PartSolution::Grid(_)
+                | PartSolution::Manual(_)
+                | PartSolution::None => (self == other).then_some(Ordering::Equal),
+            },
+            PartSolution::Grid(_) | PartSolution::Manual(_) | PartSolution::None => {
+                (self == other).then_some(Ordering::Equal)
+            },
+            PartSolution::I32(_)
+            | PartSolution::U32(_)
+            | PartSolution::I64(_)
+            | PartSolution::U64(_)
+            | PartSolution::ISize(_)
+            | PartSolution::USize(_)
+            | PartSolution::I128(_)
+            | PartSolution::U128(_) => Some(integer(self)?.cmp(&integer(other)?)),
+        }
+    }
+}
+
+impl PartialEq<PartSolution> for String {
+    fn eq(&self, other: &PartSolution) -> bool {
+        match *other {
+            PartSolution::String(ref string) => string == self,
+            PartSolution::Coordinates(..) | PartSolution::Joined { .. } => {
+                *self == format!("{}", other)
+            },
+            PartSolution::Manual(_) => other.read().as_ref() == Some(self),
+            PartSolution::I32(_)
+            | PartSolution::U32(_)
+            | PartSolution::I64(_)
+            | PartSolution::U64(_)
+            | PartSolution::ISize(_)
+            | PartSolution::USize(_)
+            | PartSolution::I128(_)
+            | PartSolution::U128(_)
+            | PartSolution::Vec(_)
+            | PartSolution::Grid(_)
+            | PartSolution::None => false,
+        }
+    }
+}
+
+impl PartialOrd<PartSolution> for String {
+    fn partial_cmp(&self, other: &PartSolution) -> Option<Ordering> {
+        if let PartSolution::String(ref string) = *other {
+            Some(self.cmp(string))
+        } else {
+            (*self == *other).then_some(Ordering::Equal)
+        }
+    }
+}
+
+impl PartialEq<PartSolution> for Vec<String> {
+    fn eq(&self, other: &PartSolution) -> bool {
+        match *other {
+            PartSolution::Vec(ref strings) => strings == self,
+            PartSolution::Grid(ref grid) => grid.lines() == *self,
+            PartSolution::I32(_)
+            | PartSolution::U32(_)
+            | PartSolution::I64(_)
+            | PartSolution::U64(_)
+            | PartSolution::ISize(_)
+            | PartSolution::USize(_)
+            | PartSolution::I128(_)
+            | PartSolution::U128(_)
+            | PartSolution::String(_)
+            | PartSolution::Coordinates(..)
+            | PartSolution::Joined { .. }
+            | PartSolution::Manual(_)
+            | PartSolution::None => false,
+        }
+    }
+}
+
+impl PartialOrd<PartSolution> for Vec<String> {
+    fn partial_cmp(&self, other: &PartSolution) -> Option<Ordering> {
+        if let PartSolution::Vec(ref strings) = *other {
+            Some(self.cmp(strings))
+        } else {
+            (*self == *other).then_some(Ordering::Equal)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use proptest::prelude::{Just, Strategy, any, prop, prop_assert_eq, prop_oneof, proptest};
+
+    use crate::shared::PartSolution;
+    use crate::shared::grids::grid::Grid;
+
+    const L: &str = "#...\n#...\n#...\n#...\n#...\n####";
+    const E: &str = "####\n#...\n###.\n#...\n#...\n####";
+    const UNREADABLE: &str = "####\n####";
+
+    /// Compares two integers by their decimal representation, independently of their types.
+    fn reference(left: &PartSolution, right: &PartSolution) -> Ordering {
+        fn magnitude(left: &str, right: &str) -> Ordering {
+            left.len().cmp(&right.len()).then_with(|| left.cmp(right))
+        }
+
+        let (left, right) = (left.to_string(), right.to_string());
+
+        match (left.strip_prefix('-'), right.strip_prefix('-')) {
+            (Some(left), Some(right)) => magnitude(right, left),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => magnitude(&left, &right),
+        }
+    }
+
+    /// Compares the integer inside `left`, as its own type, with `right`.
+    fn typed(left: &PartSolution, right: &PartSolution) -> (bool, Option<Ordering>) {
+        match *left {
+            PartSolution::I32(value) => (value == *right, value.partial_cmp(right)),
+            PartSolution::U32(value) => (value == *right, value.partial_cmp(right)),
+            PartSolution::I64(value) => (value == *right, value.partial_cmp(right)),
+            PartSolution::U64(value) => (value == *right, value.partial_cmp(right)),
+            PartSolution::ISize(value) => (value == *right, value.partial_cmp(right)),
+            PartSolution::USize(value) => (value == *right, value.partial_cmp(right)),
+            PartSolution::I128(value) => (value == *right, value.partial_cmp(right)),
+            PartSolution::U128(value) => (value == *right, value.partial_cmp(right)),
+            PartSolution::String(ref value) => (*value == *right, value.partial_cmp(right)),
+            PartSolution::Vec(ref value) => (*value == *right, value.partial_cmp(right)),
+            PartSolution::Coordinates(..)
+            | PartSolution::Joined { .. }
+            | PartSolution::Grid(_)
+            | PartSolution::Manual(_)
+            | PartSolution::None => panic!("Not a typed value"),
+        }
+    }
+
+    fn integers() -> impl Strategy<Value = PartSolution> {
+        prop_oneof![
+            any::<i32>().prop_map(PartSolution::I32),
+            any::<u32>().prop_map(PartSolution::U32),
+            any::<i64>().prop_map(PartSolution::I64),
+            any::<u64>().prop_map(PartSolution::U64),
+            any::<isize>().prop_map(PartSolution::ISize),
+            any::<usize>().prop_map(PartSolution::USize),
+            any::<i128>().prop_map(PartSolution::I128),
+            any::<u128>().prop_map(PartSolution::U128),
+            // small values, so different types regularly hold the same value
+            (-3_i32..=3).prop_map(PartSolution::I32),
+            (0_u64..=3).prop_map(PartSolution::U64),
+            (-3_i128..=3).prop_map(PartSolution::I128),
+            (0_usize..=3).prop_map(PartSolution::USize),
+        ]
+    }
+
+    fn others() -> impl Strategy<Value = PartSolution> {
+        prop_oneof![
+            any::<String>().prop_map(PartSolution::String),
+            any::<Vec<String>>().prop_map(PartSolution::Vec),
+            // few distinct values, so equal ones and their written forms come up regularly
+            (-2_i64..=2, -2_i64..=2).prop_map(|(x, y)| PartSolution::Coordinates(x, y)),
+            (-2_i64..=2, -2_i64..=2)
+                .prop_map(|(x, y)| PartSolution::String(format!("{},{}", x, y))),
+            "[ab]([,-][ab]){0,2}".prop_map(PartSolution::String),
+            "[LE]".prop_map(PartSolution::String),
+            (
+                prop::collection::vec("[ab]", 0..3),
+                prop_oneof![Just(","), Just("-")]
+            )
+                .prop_map(|(items, separator)| PartSolution::joined(items, separator)),
+            prop::collection::vec("[ab,-]{0,3}", 1..3).prop_map(PartSolution::Vec),
+            prop::collection::vec(
+                prop::collection::vec(prop_oneof![Just('a'), Just('b')], 2),
+                1..3
+            )
+            .prop_map(|rows| PartSolution::Grid(Grid::new(rows))),
+            prop_oneof![Just(L), Just(E), Just(UNREADABLE)].prop_map(|picture| {
+                PartSolution::Manual(Grid::new(
+                    picture.lines().map(|line| line.chars().collect()).collect(),
+                ))
+            }),
+            Just(PartSolution::None),
+        ]
+    }
+
+    /// The minimum and maximum of every integer type and the values around them, as every type
+    /// that can hold them.
+    fn boundaries() -> Vec<PartSolution> {
+        let mut values = vec![0, 1, -1];
+
+        for bits in [31, 32, 63, 64] {
+            let power = 1_i128 << bits;
+
+            values.extend([power - 1, power, power + 1, -power, -power - 1]);
+        }
+
+        values.extend([i128::MIN, i128::MIN + 1, i128::MAX]);
+
+        let mut boundaries = vec![];
+
+        for value in values {
+            boundaries.extend(i32::try_from(value).ok().map(PartSolution::I32));
+            boundaries.extend(u32::try_from(value).ok().map(PartSolution::U32));
+            boundaries.extend(i64::try_from(value).ok().map(PartSolution::I64));
+            boundaries.extend(u64::try_from(value).ok().map(PartSolution::U64));
+            boundaries.extend(isize::try_from(value).ok().map(PartSolution::ISize));
+            boundaries.extend(usize::try_from(value).ok().map(PartSolution::USize));
+            boundaries.push(PartSolution::I128(value));
+            boundaries.extend(u128::try_from(value).ok().map(PartSolution::U128));
+        }
+
+        boundaries.extend([
+            PartSolution::U128(u128::MAX - 1),
+            PartSolution::U128(u128::MAX),
+            PartSolution::U128(i128::MAX.unsigned_abs() + 1),
+        ]);
+
+        boundaries
+    }
+
+    #[test]
+    fn boundary_matrix() {
+        let boundaries = boundaries();
+
+        for left in &boundaries {
+            for right in &boundaries {
+                let expected = reference(left, right);
+
+                assert_eq!(
+                    (expected == Ordering::Equal, Some(expected)),
+                    typed(left, right),
+                    "{:?} compared with {:?}",
+                    left,
+                    right
+                );
+                assert_eq!(
+                    Some(expected),
+                    left.partial_cmp(right),
+                    "{:?} compared with {:?}",
+                    left,
+                    right
+                );
+                assert_eq!(
+                    expected == Ordering::Equal,
+                    *left == *right,
+                    "{:?} equal to {:?}",
+                    left,
+                    right
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn wide() {
+        let wide = u128::from(u64::MAX) + 1;
+
+        assert_eq!(PartSolution::U128(wide), PartSolution::from(wide));
+        assert_eq!("18446744073709551616", PartSolution::U128(wide).to_string());
+        assert_eq!(PartSolution::U64(5), PartSolution::I128(5));
+        assert_eq!(PartSolution::I128(-5), PartSolution::I32(-5));
+        assert_ne!(PartSolution::U128(wide), PartSolution::U64(u64::MAX));
+        assert_ne!(PartSolution::U128(u128::MAX), PartSolution::I128(-1));
+
+        assert_eq!(
+            Some(Ordering::Less),
+            i32::MAX.partial_cmp(&PartSolution::U128(wide))
+        );
+        assert_eq!(
+            Some(Ordering::Less),
+            u64::MAX.partial_cmp(&PartSolution::U128(wide))
+        );
+        assert_eq!(
+            Some(Ordering::Greater),
+            0_u128.partial_cmp(&PartSolution::I128(-1))
+        );
+        assert_eq!(
+            Some(Ordering::Greater),
+            wide.partial_cmp(&PartSolution::U64(u64::MAX))
+        );
+        assert_eq!(
+            Some(Ordering::Less),
+            (-1_i128).partial_cmp(&PartSolution::U128(0))
+        );
+    }
+
+    #[test]
+    fn strings() {
+        let abc = PartSolution::String("abc".into());
+        let list = PartSolution::Vec(vec!["a".into(), "b".into()]);
+
+        assert_eq!(Some(Ordering::Less), String::from("abb").partial_cmp(&abc));
+        assert_eq!(
+            Some(Ordering::Greater),
+            vec![String::from("b")].partial_cmp(&list)
+        );
+        assert_eq!(None, abc.partial_cmp(&list));
+        assert_eq!(None, PartSolution::I32(1).partial_cmp(&abc));
+        assert_ne!(PartSolution::String("1".into()), PartSolution::I32(1));
+    }
+
+    #[test]
+    fn structured() {
+        let coordinates = PartSolution::Coordinates(50, 23);
+        let password = PartSolution::joined(vec!["co".into(), "de".into(), "ka".into()], ",");
+        let grid = PartSolution::Grid(Grid::new(vec![vec!['#', '.'], vec!['.', '#']]));
+
+        assert_eq!("50,23", coordinates.to_string());
+        assert_eq!("co,de,ka", password.to_string());
+        assert_eq!("\n#.\n.#", grid.to_string());
+
+        assert_eq!(PartSolution::String("50,23".into()), coordinates);
+        assert_eq!(coordinates, PartSolution::String("50,23".into()));
+        assert_ne!(PartSolution::String("50, 23".into()), coordinates);
+        assert_eq!(
+            Some(Ordering::Less),
+            coordinates.partial_cmp(&PartSolution::Coordinates(50, 24))
+        );
+
+        assert_eq!(PartSolution::String("co,de,ka".into()), password);
+        assert_ne!(
+            PartSolution::joined(vec!["co".into(), "de".into(), "ka".into()], "-"),
+            password
+        );
+        assert_ne!(PartSolution::Vec(vec!["co,de,ka".into()]), password);
+
+        assert_eq!(PartSolution::Vec(vec!["#.".into(), ".#".into()]), grid);
+        assert_ne!(
+            PartSolution::Grid(Grid::new(vec![vec!['#', '.'], vec!['#', '.']])),
+            grid
+        );
+        assert_eq!(None, grid.partial_cmp(&PartSolution::String("#.".into())));
+
+        let manual = PartSolution::Manual(Grid::new(
+            L.lines().map(|line| line.chars().collect()).collect(),
+        ));
+
+        assert_eq!(
+            cfg!(feature = "ocr"),
+            PartSolution::String("L".into()) == manual
+        );
+        assert_ne!(
+            PartSolution::Vec(L.lines().map(Into::into).collect()),
+            manual
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn integers_compare_by_value(left in integers(), right in integers()) {
+            let expected = reference(&left, &right);
+
+            prop_assert_eq!((expected == Ordering::Equal, Some(expected)), typed(&left, &right));
+            prop_assert_eq!(Some(expected), left.partial_cmp(&right));
+            prop_assert_eq!(expected == Ordering::Equal, left == right);
+        }
+
+        #[test]
+        fn comparisons_are_antisymmetric(
+            left in prop_oneof![integers(), others()],
+            right in prop_oneof![integers(), others()],
+        ) {
+            prop_assert_eq!(left == right, right == left);
+            prop_assert_eq!(
+                left.partial_cmp(&right),
+                right.partial_cmp(&left).map(Ordering::reverse)
+            );
+        }
+
+        #[test]
+        fn integers_are_unordered_with_the_others(left in integers(), right in others()) {
+            prop_assert_eq!((false, None), typed(&left, &right));
+            prop_assert_eq!(None, left.partial_cmp(&right));
+            prop_assert_eq!(None, right.partial_cmp(&left));
+        }
+    }
+}
@@ -2,7 +2,13 @@ use std::error::Error;
 use std::fmt::Display;
 use std::str::FromStr;
 
-/// A valid day number of advent (i.e. an integer in range 1 to 25).
+#[cfg(feature = "serde")]
+use serde::de::Error as _;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A valid day number of advent (i.e. an integer in range 1 to 25). Not every event has all of
+/// them, see [`PuzzleId`](super::puzzle::PuzzleId) for a day of a specific event.
 ///
 /// # Display
 /// This value displays as a two digit number.
@@ -36,7 +42,7 @@ impl Day {
 
     /// Converts the [`Day`] into an [`u8`].
     #[must_use]
-    pub fn into_inner(self) -> u8 {
+    pub const fn into_inner(self) -> u8 {
         self.0
     }
 }
@@ -83,6 +89,25 @@ impl Display for DayFromStrError {
     }
 }
 
+/* -------------------------------------------------------------------------- */
+
+/// Serializes as its number.
+#[cfg(feature = "serde")]
+impl Serialize for Day {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Day {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let day = u8::deserialize(deserializer)?;
+
+        Self::try_new(day).ok_or_else(|| D::Error::custom(DayFromStrError {}))
+    }
+}
+
 /// Creates a [`Day`] value in a const context.
 #[macro_export]
 macro_rules! day {
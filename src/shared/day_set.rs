@@ -0,0 +1,301 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use super::day::Day;
+use super::tag::Tag;
+use super::year::Year;
+
+/// One of the comma separated items of a [`DaySet`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Selector {
+    /// The days from the first one up to and including the second one, or the last day of the
+    /// event when there is none.
+    Range(Day, Option<Day>),
+    All,
+    Odd,
+    Even,
+    Tagged(Tag),
+}
+
+impl Selector {
+    fn contains(self, day: Day, tags: &[Tag]) -> bool {
+        match self {
+            Selector::Range(start, end) => start <= day && end.is_none_or(|end| day <= end),
+            Selector::All => true,
+            Selector::Odd => !day.into_inner().is_multiple_of(2),
+            Selector::Even => day.into_inner().is_multiple_of(2),
+            Selector::Tagged(tag) => tags.contains(&tag),
+        }
+    }
+}
+
+impl Display for Selector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Selector::Range(start, Some(end)) if start == end => {
+                write!(f, "{}", start.into_inner())
+            },
+            Selector::Range(start, Some(end)) => {
+                write!(f, "{}-{}", start.into_inner(), end.into_inner())
+            },
+            Selector::Range(start, None) => write!(f, "{}-", start.into_inner()),
+            Selector::All => f.write_str("all"),
+            Selector::Odd => f.write_str("odd"),
+            Selector::Even => f.write_str("even"),
+            Selector::Tagged(tag) => write!(f, "{}", tag),
+        }
+    }
+}
+
+/// A selection of days, like `1-5,7,20-`. Which days that are depends on the event, for its last
+/// day, and on the [`Tag`]s of the days' solutions.
+///
+/// The items are separated by commas, each one is:
+/// - a day, like `7`
+/// - a range of days, like `1-5`, `3..9` or `3..=9`, or `20-` up to the last day of the event
+/// - `all`, `odd` or `even`
+/// - a [`Tag`], like `grids`, for the days whose solution has it
+///
+/// # Display
+/// This value displays as its items, with the ranges written as `1-5`.
+///
+/// ```
+/// # use advent_of_code_2024::shared::day_set::DaySet;
+/// # use advent_of_code_2024::shared::year::Year;
+/// let days: DaySet = "1-3,odd,20-".parse().unwrap();
+/// let days = days.iter(Year::try_new(2024).unwrap(), |_| &[]).map(|day| day.into_inner());
+/// assert_eq!(
+///     days.collect::<Vec<_>>(),
+///     [1, 2, 3, 5, 7, 9, 11, 13, 15, 17, 19, 20, 21, 22, 23, 24, 25]
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DaySet {
+    selectors: Vec<Selector>,
+}
+
+impl DaySet {
+    /// Every day of the event.
+    #[must_use]
+    pub fn all() -> Self {
+        DaySet {
+            selectors: vec![Selector::All],
+        }
+    }
+
+    /// Whether `day`, whose solution has `tags`, is selected.
+    #[must_use]
+    pub fn contains(&self, day: Day, tags: &[Tag]) -> bool {
+        self.selectors
+            .iter()
+            .any(|selector| selector.contains(day, tags))
+    }
+
+    /// The selected days of `year`'s event, in order, with `tags` giving the tags of a day's
+    /// solution.
+    pub fn iter<F>(&self, year: Year, tags: F) -> impl Iterator<Item = Day>
+    where
+        F: Fn(Day) -> &'static [Tag],
+    {
+        (1..=year.last_day().into_inner())
+            .filter_map(Day::try_new)
+            .filter(move |&day| self.contains(day, tags(day)))
+    }
+
+    /// Whether every day given by its number is a day of `year`'s event.
+    #[must_use]
+    pub fn fits(&self, year: Year) -> bool {
+        let last_day = year.last_day();
+
+        self.selectors.iter().all(|selector| match *selector {
+            Selector::Range(start, end) => {
+                start <= last_day && end.is_none_or(|end| end <= last_day)
+            },
+            Selector::All | Selector::Odd | Selector::Even | Selector::Tagged(_) => true,
+        })
+    }
+}
+
+impl From<RangeInclusive<Day>> for DaySet {
+    fn from(days: RangeInclusive<Day>) -> Self {
+        DaySet {
+            selectors: vec![Selector::Range(*days.start(), Some(*days.end()))],
+        }
+    }
+}
+
+impl Display for DaySet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (index, selector) in self.selectors.iter().enumerate() {
+            if index > 0 {
+                f.write_str(",")?;
+            }
+
+            write!(f, "{}", selector)?;
+        }
+
+        Ok(())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Selector {
+    type Err = DaySetFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let day = |day: &str| day.parse::<Day>().map_err(|_| DaySetFromStrError {});
+
+        match s {
+            "all" => return Ok(Selector::All),
+            "odd" => return Ok(Selector::Odd),
+            "even" => return Ok(Selector::Even),
+            _ => {},
+        }
+
+        if let Ok(tag) = s.parse() {
+            return Ok(Selector::Tagged(tag));
+        }
+
+        let (start, end) = if let Some((start, end)) = s.split_once("..=") {
+            (day(start)?, Some(day(end)?))
+        } else if let Some((start, end)) = s.split_once("..") {
+            let end = day(end)?
+                .into_inner()
+                .checked_sub(1)
+                .and_then(Day::try_new)
+                .ok_or(DaySetFromStrError {})?;
+
+            (day(start)?, Some(end))
+        } else if let Some((start, end)) = s.split_once('-') {
+            let end = if end.is_empty() {
+                None
+            } else {
+                Some(day(end)?)
+            };
+
+            (day(start)?, end)
+        } else {
+            (day(s)?, Some(day(s)?))
+        };
+
+        if end.is_some_and(|end| start > end) {
+            return Err(DaySetFromStrError {});
+        }
+
+        Ok(Selector::Range(start, end))
+    }
+}
+
+impl FromStr for DaySet {
+    type Err = DaySetFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let selectors = s
+            .split(',')
+            .map(|selector| selector.trim().parse())
+            .collect::<Result<_, _>>()?;
+
+        Ok(DaySet { selectors })
+    }
+}
+
+/// An error which can be returned when parsing a [`DaySet`].
+#[expect(clippy::module_name_repetitions, reason = "Name clarity")]
+#[derive(Debug)]
+pub struct DaySetFromStrError {}
+
+impl Error for DaySetFromStrError {}
+
+impl Display for DaySetFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(
+            "expecting comma separated days, non-empty ranges of days like `1-5` or `20-`, `all`, `odd`, `even` or tags",
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DaySet;
+    use crate::day;
+    use crate::shared::day::Day;
+    use crate::shared::tag::Tag;
+    use crate::shared::year::Year;
+
+    fn days(set: &str, year: u16) -> Vec<u8> {
+        let tags = |day: Day| -> &'static [Tag] {
+            match day.into_inner() {
+                4 | 6 => &[Tag::Grids],
+                10 => &[Tag::Grids, Tag::Pathfinding],
+                _ => &[],
+            }
+        };
+
+        set.parse::<DaySet>()
+            .unwrap()
+            .iter(Year::try_new(year).unwrap(), tags)
+            .map(Day::into_inner)
+            .collect()
+    }
+
+    #[test]
+    fn selectors() {
+        assert_eq!(
+            vec![1, 2, 3, 4, 5, 7, 20, 21, 22, 23, 24, 25],
+            days("1-5,7,20-", 2024)
+        );
+        assert_eq!(vec![3, 4, 5, 6, 7, 8], days("3..9", 2024));
+        assert_eq!(vec![3, 4, 5, 6, 7, 8, 9], days("3..=9", 2024));
+        assert_eq!((1..=25).collect::<Vec<_>>(), days("all", 2024));
+        assert_eq!((1..=12).collect::<Vec<_>>(), days("all", 2025));
+        assert_eq!(vec![10, 11, 12], days("10-", 2025));
+        assert_eq!(vec![2, 4, 6, 8, 10, 12], days("even", 2025));
+        assert_eq!(vec![1, 3, 5, 7, 9, 11], days("odd", 2025));
+    }
+
+    #[test]
+    fn tags() {
+        assert_eq!(vec![4, 6, 10], days("grids", 2024));
+        assert_eq!(vec![1, 10], days("pathfinding, 1", 2024));
+        assert_eq!(Vec::<u8>::new(), days("math", 2024));
+    }
+
+    #[test]
+    fn display() {
+        for set in ["1-5,7,20-", "all,odd,even", "grids,dynamic-programming"] {
+            assert_eq!(set, set.parse::<DaySet>().unwrap().to_string());
+        }
+
+        assert_eq!("3-8", "3..9".parse::<DaySet>().unwrap().to_string());
+        assert_eq!(
+            DaySet::from(day!(7)..=day!(7)),
+            "7".parse::<DaySet>().unwrap()
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        for set in [
+            "", "1,", "0", "26", "9-3", "1..1", "-5", "1--2", "grid", "all-",
+        ] {
+            assert!(
+                set.parse::<DaySet>().is_err(),
+                "`{}` is not a set of days",
+                set
+            );
+        }
+    }
+
+    #[test]
+    fn fits() {
+        let year = Year::try_new(2025).unwrap();
+
+        assert!("1-12,odd,grids".parse::<DaySet>().unwrap().fits(year));
+        assert!(!"13".parse::<DaySet>().unwrap().fits(year));
+        assert!(!"10-13".parse::<DaySet>().unwrap().fits(year));
+        assert!(!"13-".parse::<DaySet>().unwrap().fits(year));
+    }
+}
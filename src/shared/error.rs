@@ -0,0 +1,91 @@
+use std::error::Error;
+use std::fmt::Display;
+
+/// An error which can be returned when solving a part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    /// The input doesn't have the expected shape. `line` and `column` are 1-based.
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// The input is well-formed, but cannot be solved.
+    Invalid(String),
+}
+
+impl SolveError {
+    /// Creates a [`SolveError::Parse`] at the 1-based `line` and `column`.
+    #[must_use]
+    pub fn parse<M: Into<String>>(line: usize, column: usize, message: M) -> Self {
+        SolveError::Parse {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    /// Creates a [`SolveError::Parse`] at the byte `offset` into `input`.
+    #[must_use]
+    pub fn parse_at<M: Into<String>>(input: &str, offset: usize, message: M) -> Self {
+        let before = input.get(..offset).unwrap_or(input);
+
+        let line = before.matches('\n').count() + 1;
+        let column = before
+            .rfind('\n')
+            .map_or(before, |newline| {
+                before.get(newline + 1..).unwrap_or_default()
+            })
+            .chars()
+            .count()
+            + 1;
+
+        Self::parse(line, column, message)
+    }
+}
+
+impl Error for SolveError {}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            SolveError::Parse {
+                line,
+                column,
+                ref message,
+            } => write!(f, "line {}, column {}: {}", line, column, message),
+            SolveError::Invalid(ref message) => f.write_str(message),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SolveError;
+
+    #[test]
+    fn parse_at() {
+        let input = "abc\ndef\nghi";
+
+        assert_eq!(
+            SolveError::parse(1, 1, "x"),
+            SolveError::parse_at(input, 0, "x")
+        );
+        assert_eq!(
+            SolveError::parse(2, 3, "x"),
+            SolveError::parse_at(input, 6, "x")
+        );
+        assert_eq!(
+            SolveError::parse(3, 1, "x"),
+            SolveError::parse_at(input, 8, "x")
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            "line 42, column 1: expected `Button A:`",
+            SolveError::parse(42, 1, "expected `Button A:`").to_string()
+        );
+    }
+}
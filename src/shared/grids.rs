@@ -1,11 +1,13 @@
 pub mod grid;
 pub mod infinite_grid;
+pub mod point;
 
 use std::cmp::PartialEq;
-use std::ops::Index;
-use std::slice::Iter;
 
-#[derive(PartialEq, Eq, Debug)]
+use point::Vector;
+
+/// One of the four horizontal and vertical directions, in clockwise order from `Up`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum HorizontalVerticalDirection {
     Up,
     Right,
@@ -13,7 +15,66 @@ pub enum HorizontalVerticalDirection {
     Left,
 }
 
-#[derive(PartialEq, Eq, Debug)]
+impl HorizontalVerticalDirection {
+    /// Every direction, in clockwise order from `Up`.
+    #[must_use]
+    pub const fn all() -> [Self; 4] {
+        [Self::Up, Self::Right, Self::Down, Self::Left]
+    }
+
+    /// The direction a quarter turn counterclockwise.
+    #[must_use]
+    pub const fn turn_left(self) -> Self {
+        match self {
+            Self::Up => Self::Left,
+            Self::Right => Self::Up,
+            Self::Down => Self::Right,
+            Self::Left => Self::Down,
+        }
+    }
+
+    /// The direction a quarter turn clockwise.
+    #[must_use]
+    pub const fn turn_right(self) -> Self {
+        match self {
+            Self::Up => Self::Right,
+            Self::Right => Self::Down,
+            Self::Down => Self::Left,
+            Self::Left => Self::Up,
+        }
+    }
+
+    /// The direction pointing the other way.
+    #[must_use]
+    pub const fn opposite(self) -> Self {
+        match self {
+            Self::Up => Self::Down,
+            Self::Right => Self::Left,
+            Self::Down => Self::Up,
+            Self::Left => Self::Right,
+        }
+    }
+
+    /// A single step in this direction.
+    #[must_use]
+    pub const fn delta(self) -> Vector {
+        match self {
+            Self::Up => Vector::new(-1, 0),
+            Self::Right => Vector::new(0, 1),
+            Self::Down => Vector::new(1, 0),
+            Self::Left => Vector::new(0, -1),
+        }
+    }
+}
+
+impl From<HorizontalVerticalDirection> for Vector {
+    fn from(direction: HorizontalVerticalDirection) -> Self {
+        direction.delta()
+    }
+}
+
+/// One of the eight horizontal, vertical and diagonal directions, in clockwise order from `Up`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum HorizontalVerticalDiagonalDirection {
     Up,
     UpRight,
@@ -25,40 +86,168 @@ pub enum HorizontalVerticalDiagonalDirection {
     UpLeft,
 }
 
-type HorizontalVerticalNeighbors<T> = Vec<((T, T), HorizontalVerticalDirection)>;
-type HorizontalVerticalDiagonalNeighbors<T> = Vec<((T, T), HorizontalVerticalDiagonalDirection)>;
+impl HorizontalVerticalDiagonalDirection {
+    /// Every direction, in clockwise order from `Up`.
+    #[must_use]
+    pub const fn all() -> [Self; 8] {
+        [
+            Self::Up,
+            Self::UpRight,
+            Self::Right,
+            Self::DownRight,
+            Self::Down,
+            Self::DownLeft,
+            Self::Left,
+            Self::UpLeft,
+        ]
+    }
 
-pub trait Neighbors {
+    /// The direction an eighth turn counterclockwise.
+    #[must_use]
+    pub const fn turn_left(self) -> Self {
+        match self {
+            Self::Up => Self::UpLeft,
+            Self::UpRight => Self::Up,
+            Self::Right => Self::UpRight,
+            Self::DownRight => Self::Right,
+            Self::Down => Self::DownRight,
+            Self::DownLeft => Self::Down,
+            Self::Left => Self::DownLeft,
+            Self::UpLeft => Self::Left,
+        }
+    }
+
+    /// The direction an eighth turn clockwise.
+    #[must_use]
+    pub const fn turn_right(self) -> Self {
+        match self {
+            Self::Up => Self::UpRight,
+            Self::UpRight => Self::Right,
+            Self::Right => Self::DownRight,
+            Self::DownRight => Self::Down,
+            Self::Down => Self::DownLeft,
+            Self::DownLeft => Self::Left,
+            Self::Left => Self::UpLeft,
+            Self::UpLeft => Self::Up,
+        }
+    }
+
+    /// The direction pointing the other way.
+    #[must_use]
+    pub const fn opposite(self) -> Self {
+        match self {
+            Self::Up => Self::Down,
+            Self::UpRight => Self::DownLeft,
+            Self::Right => Self::Left,
+            Self::DownRight => Self::UpLeft,
+            Self::Down => Self::Up,
+            Self::DownLeft => Self::UpRight,
+            Self::Left => Self::Right,
+            Self::UpLeft => Self::DownRight,
+        }
+    }
+
+    /// A single step in this direction.
+    #[must_use]
+    pub const fn delta(self) -> Vector {
+        match self {
+            Self::Up => Vector::new(-1, 0),
+            Self::UpRight => Vector::new(-1, 1),
+            Self::Right => Vector::new(0, 1),
+            Self::DownRight => Vector::new(1, 1),
+            Self::Down => Vector::new(1, 0),
+            Self::DownLeft => Vector::new(1, -1),
+            Self::Left => Vector::new(0, -1),
+            Self::UpLeft => Vector::new(-1, -1),
+        }
+    }
+}
+
+impl From<HorizontalVerticalDiagonalDirection> for Vector {
+    fn from(direction: HorizontalVerticalDiagonalDirection) -> Self {
+        direction.delta()
+    }
+}
+
+type HorizontalVerticalNeighbor<T> = ((T, T), HorizontalVerticalDirection);
+type HorizontalVerticalDiagonalNeighbor<T> = ((T, T), HorizontalVerticalDiagonalDirection);
+
+pub trait Neighbors: GridIter {
     type Index: GridIndex;
 
-    /// Gets the horizontal and vertical neighbors
+    /// The cell at `row_index` and `column_index`
+    fn cell(&self, row_index: Self::Index, column_index: Self::Index) -> &Self::Cell;
+
+    /// Gets the horizontal and vertical neighbors, clockwise from `Up`
     fn hv_neighbors(
         &self,
         row_index: Self::Index,
         column_index: Self::Index,
-    ) -> HorizontalVerticalNeighbors<Self::Index>;
+    ) -> impl Iterator<Item = HorizontalVerticalNeighbor<Self::Index>>;
 
-    /// Gets the horizontal, vertical, and diagonal neighbors
+    /// Gets the horizontal, vertical, and diagonal neighbors, clockwise from `Up`
     fn hvd_neighbors(
         &self,
         row_index: Self::Index,
         column_index: Self::Index,
-    ) -> HorizontalVerticalDiagonalNeighbors<Self::Index>;
+    ) -> impl Iterator<Item = HorizontalVerticalDiagonalNeighbor<Self::Index>>;
+
+    /// Gets the horizontal and vertical neighbors whose cell matches `predicate`
+    fn hv_neighbors_where<P>(
+        &self,
+        row_index: Self::Index,
+        column_index: Self::Index,
+        mut predicate: P,
+    ) -> impl Iterator<Item = HorizontalVerticalNeighbor<Self::Index>>
+    where
+        P: FnMut(&Self::Cell) -> bool,
+    {
+        self.hv_neighbors(row_index, column_index)
+            .filter(move |&((row_index, column_index), _)| {
+                predicate(self.cell(row_index, column_index))
+            })
+    }
+
+    /// Gets the horizontal, vertical, and diagonal neighbors whose cell matches `predicate`
+    fn hvd_neighbors_where<P>(
+        &self,
+        row_index: Self::Index,
+        column_index: Self::Index,
+        mut predicate: P,
+    ) -> impl Iterator<Item = HorizontalVerticalDiagonalNeighbor<Self::Index>>
+    where
+        P: FnMut(&Self::Cell) -> bool,
+    {
+        self.hvd_neighbors(row_index, column_index).filter(
+            move |&((row_index, column_index), _)| predicate(self.cell(row_index, column_index)),
+        )
+    }
 }
 
 pub trait GridIter {
-    type GridRow;
+    type Cell;
 
-    fn get_grid(&self) -> &Vec<Self::GridRow>;
     fn get_row_length(&self) -> usize;
     fn get_column_length(&self) -> usize;
 
-    fn row_iter(&'_ self) -> Iter<'_, Self::GridRow> {
-        self.get_grid().iter()
+    /// The cells of the row at `row_index`, in order.
+    ///
+    /// # Panics
+    /// When `row_index` is out of bounds
+    fn row(&self, row_index: usize) -> &[Self::Cell];
+
+    fn row_iter(&'_ self) -> RowIter<'_, Self>
+    where
+        Self: Sized,
+    {
+        RowIter::new(self)
     }
 
-    fn y_iter(&'_ self) -> Iter<'_, Self::GridRow> {
-        self.get_grid().iter()
+    fn y_iter(&'_ self) -> RowIter<'_, Self>
+    where
+        Self: Sized,
+    {
+        RowIter::new(self)
     }
 
     fn x_iter(&'_ self) -> XIter<'_, Self>
@@ -90,11 +279,47 @@ pub trait GridIter {
     }
 }
 
-pub trait GridIndex {}
+pub trait GridIndex: Copy {}
 
 impl GridIndex for usize {}
 impl GridIndex for isize {}
 
+#[must_use]
+pub struct RowIter<'g, G> {
+    grid: &'g G,
+    row_index: usize,
+    row_length: usize,
+}
+
+impl<'g, G> Iterator for RowIter<'g, G>
+where
+    G: GridIter,
+{
+    type Item = &'g [G::Cell];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.row_index < self.row_length {
+            let row = self.grid.row(self.row_index);
+
+            self.row_index += 1;
+
+            Some(row)
+        } else {
+            None
+        }
+    }
+}
+
+impl<'g, G: GridIter> RowIter<'g, G> {
+    fn new(grid: &'g G) -> RowIter<'g, G> {
+        Self {
+            grid,
+            row_index: 0,
+            row_length: grid.get_row_length(),
+        }
+    }
+}
+
 #[must_use]
 pub struct XIter<'g, G> {
     grid: &'g G,
@@ -105,16 +330,14 @@ pub struct XIter<'g, G> {
 impl<'g, G> Iterator for XIter<'g, G>
 where
     G: GridIter,
-    G::GridRow: Index<usize>,
 {
-    type Item = Vec<&'g <G::GridRow as Index<usize>>::Output>;
+    type Item = Vec<&'g G::Cell>;
 
     fn next(&mut self) -> Option<Self::Item> {
         if self.column_index < self.column_length {
             let column = self
                 .grid
-                .get_grid()
-                .iter()
+                .row_iter()
                 .map(|row| &row[self.column_index])
                 .collect();
 
@@ -146,15 +369,8 @@ pub struct XYValueIter<'g, G> {
     column_length: usize,
 }
 
-impl<G: GridIter> XYValueIter<'_, G>
-where
-    G: GridIter + Index<usize, Output = G::GridRow>,
-    G::GridRow: Index<usize>,
-{
-    pub fn find<P: Fn(&<G::GridRow as Index<usize>>::Output) -> bool>(
-        &self,
-        predicate: P,
-    ) -> Option<(usize, usize)> {
+impl<G: GridIter> XYValueIter<'_, G> {
+    pub fn find<P: Fn(&G::Cell) -> bool>(&self, predicate: P) -> Option<(usize, usize)> {
         // The order here is incorrect, it goes by row by row, which is unexpected for xy (column by column)
         for ((row_index, column_index), v) in self.grid.row_column_index_value_iter() {
             if predicate(v) {
@@ -180,16 +396,15 @@ impl<'g, G: GridIter> XYValueIter<'g, G> {
 
 impl<'g, G> Iterator for XYValueIter<'g, G>
 where
-    G: GridIter + Index<usize, Output = G::GridRow>,
-    G::GridRow: Index<usize>,
+    G: GridIter,
 {
-    type Item = ((usize, usize), &'g <G::GridRow as Index<usize>>::Output);
+    type Item = ((usize, usize), &'g G::Cell);
 
     fn next(&mut self) -> Option<Self::Item> {
         if self.column_index < self.column_length {
             let old = (
                 (self.column_index, self.row_index),
-                &self.grid[self.column_index][self.row_index],
+                &self.grid.row(self.column_index)[self.row_index],
             );
 
             // and go next
@@ -217,15 +432,8 @@ pub struct RowColumnIndexValueIter<'g, G> {
     column_length: usize,
 }
 
-impl<G: GridIter> RowColumnIndexValueIter<'_, G>
-where
-    G: GridIter + Index<usize, Output = G::GridRow>,
-    G::GridRow: Index<usize>,
-{
-    pub fn find<P: Fn(&<G::GridRow as Index<usize>>::Output) -> bool>(
-        &self,
-        predicate: P,
-    ) -> Option<(usize, usize)> {
+impl<G: GridIter> RowColumnIndexValueIter<'_, G> {
+    pub fn find<P: Fn(&G::Cell) -> bool>(&self, predicate: P) -> Option<(usize, usize)> {
         for ((row_index, column_index), v) in self.grid.row_column_index_value_iter() {
             if predicate(v) {
                 return Some((row_index, column_index));
@@ -250,16 +458,15 @@ impl<'g, G: GridIter> RowColumnIndexValueIter<'g, G> {
 
 impl<'g, G> Iterator for RowColumnIndexValueIter<'g, G>
 where
-    G: GridIter + Index<usize, Output = G::GridRow>,
-    G::GridRow: Index<usize>,
+    G: GridIter,
 {
-    type Item = ((usize, usize), &'g <G::GridRow as Index<usize>>::Output);
+    type Item = ((usize, usize), &'g G::Cell);
 
     fn next(&mut self) -> Option<Self::Item> {
         if self.row_index < self.row_length {
             let old = (
                 (self.row_index, self.column_index),
-                &self.grid[self.row_index][self.column_index],
+                &self.grid.row(self.row_index)[self.column_index],
             );
 
             // and go next
@@ -280,6 +487,41 @@ where
 
 #[cfg(test)]
 mod tests {
+    use super::{HorizontalVerticalDiagonalDirection, HorizontalVerticalDirection};
+    use crate::shared::grids::point::Vector;
+
+    #[test]
+    fn turns() {
+        for direction in HorizontalVerticalDirection::all() {
+            assert_eq!(direction, direction.turn_left().turn_right());
+            assert_eq!(direction.opposite(), direction.turn_right().turn_right());
+            assert_eq!(
+                Vector::default(),
+                direction.delta() + direction.opposite().delta()
+            );
+        }
+
+        for direction in HorizontalVerticalDiagonalDirection::all() {
+            assert_eq!(direction, direction.turn_right().turn_left());
+            assert_eq!(
+                direction.opposite(),
+                (0..4).fold(direction, |direction, _| direction.turn_left())
+            );
+            assert_eq!(1, direction.delta().chebyshev());
+        }
+
+        assert_eq!(
+            HorizontalVerticalDirection::Left,
+            HorizontalVerticalDirection::Up.turn_left()
+        );
+        assert_eq!(
+            Vector::new(-1, 1),
+            HorizontalVerticalDiagonalDirection::Right
+                .turn_left()
+                .delta()
+        );
+    }
+
     #[test]
     fn test() {
         let first: Vec<char> = vec!['a', 'b', 'c'];
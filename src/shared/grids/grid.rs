@@ -1,30 +1,29 @@
-use std::ops::{Deref, DerefMut, Index, IndexMut};
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+use std::slice::{ChunksExact, ChunksExactMut, Iter, IterMut};
+use std::str::FromStr;
 
+#[cfg(feature = "serde")]
+use serde::de::Error as _;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::point::{Point, Vector};
 use super::{
-    GridIter, HorizontalVerticalDiagonalDirection, HorizontalVerticalDiagonalNeighbors,
-    HorizontalVerticalDirection, HorizontalVerticalNeighbors, Neighbors,
+    GridIter, HorizontalVerticalDiagonalDirection, HorizontalVerticalDiagonalNeighbor,
+    HorizontalVerticalDirection, HorizontalVerticalNeighbor, Neighbors,
 };
+use crate::shared::error::SolveError;
 
+/// A rectangular grid, indexed by row and then column.
+///
+/// The cells are stored row after row in a single `Vec`, so walking a grid row by row, or cloning
+/// it, touches one contiguous block of memory.
+#[derive(PartialEq, Eq)]
 pub struct Grid<T> {
-    data: Vec<Row<T>>,
+    data: Vec<T>,
     row_len: usize,
     column_len: usize,
-    // max_row: usize,
-    // max_column: usize,
-}
-
-impl<T> Deref for Grid<T> {
-    type Target = [Row<T>];
-
-    fn deref(&self) -> &[Row<T>] {
-        self.data.as_slice()
-    }
-}
-
-impl<T> DerefMut for Grid<T> {
-    fn deref_mut(&mut self) -> &mut [Row<T>] {
-        self.data.as_mut_slice()
-    }
 }
 
 impl<T: Clone> Clone for Grid<T> {
@@ -41,186 +40,387 @@ impl<T> Grid<T> {
     /// Builds a new grid
     ///
     /// # Panics
-    /// When rows are not equal length
+    /// When there are no rows, the rows are empty, or they are not equal length
     #[must_use]
     pub fn new(data: Vec<Vec<T>>) -> Self {
+        let rows = data.len();
+        let columns = data.first().map_or(0, Vec::len);
+
+        assert!(columns > 0, "Grid has no cells");
+
         for w in data.windows(2) {
             assert_eq!(w[0].len(), w[1].len(), "Row length differs");
         }
 
-        let rows = data.len();
-        let columns = data[0].len();
-
         Self {
-            data: data.into_iter().map(|r| Row(r)).collect(),
+            data: data.into_iter().flatten().collect(),
             row_len: rows,
             column_len: columns,
-            // max_row: rows - 1,
-            // max_column: columns - 1,
         }
     }
-}
 
-#[derive(PartialEq, Eq, PartialOrd, Ord, Debug)]
-pub struct Row<T>(Vec<T>);
+    /// Parses a grid from the lines of `input`, with `cell` turning every character into a cell.
+    /// Blank lines at the end are skipped.
+    ///
+    /// ```
+    /// # use advent_of_code_2024::shared::grids::grid::Grid;
+    /// let grid = Grid::parse("12\n34\n\n", |c| c.to_digit(10).ok_or("not a digit")).unwrap();
+    /// assert_eq!(grid[1][0], 3);
+    ///
+    /// let error = Grid::parse("12\n3x", |c| c.to_digit(10).ok_or("not a digit")).unwrap_err();
+    /// assert_eq!(error.to_string(), "line 2, column 2: not a digit, found `x`");
+    /// ```
+    ///
+    /// # Errors
+    /// When `cell` fails, at the line and column of its character, when a row's length differs
+    /// from the first one's, or when there are no rows at all
+    pub fn parse<E, F>(input: &str, mut cell: F) -> Result<Self, SolveError>
+    where
+        E: Display,
+        F: FnMut(char) -> Result<T, E>,
+    {
+        let mut data = vec![];
+        let mut column_len = None;
+
+        for (row_index, line) in rows_of(input).enumerate() {
+            let start = data.len();
+
+            for (column_index, c) in line.chars().enumerate() {
+                data.push(cell(c).map_err(|error| {
+                    SolveError::parse(
+                        row_index + 1,
+                        column_index + 1,
+                        format!("{}, found `{}`", error, c),
+                    )
+                })?);
+            }
 
-impl<T: Clone> std::clone::Clone for Row<T> {
-    fn clone(&self) -> Self {
-        Self(self.0.clone())
+            let length = data.len() - start;
+
+            match column_len {
+                None => column_len = Some(length),
+                Some(expected) if expected != length => {
+                    return Err(SolveError::parse(
+                        row_index + 1,
+                        expected.min(length) + 1,
+                        format!(
+                            "expected a row of {} cells like the first one, found {}",
+                            expected, length
+                        ),
+                    ));
+                },
+                Some(_) => {},
+            }
+        }
+
+        let Some(column_len) = column_len.filter(|&column_len| column_len > 0) else {
+            return Err(SolveError::Invalid("the grid is empty".into()));
+        };
+
+        Ok(Self {
+            row_len: data.len() / column_len,
+            column_len,
+            data,
+        })
     }
-}
 
-impl<T> Index<usize> for Row<T> {
-    type Output = T;
+    /// [Parses](Grid::parse) a grid, and finds the single cell of each of the `markers`, like
+    /// the start `S` and end `E` of a maze. The markers are turned into cells by `cell` like any
+    /// other character.
+    ///
+    /// ```
+    /// # use advent_of_code_2024::shared::grids::grid::Grid;
+    /// let (grid, [start, end]) = Grid::parse_marked("S.#\n.#E", ['S', 'E'], |c| {
+    ///     Ok::<_, &str>(c == '#')
+    /// })
+    /// .unwrap();
+    /// assert_eq!((start, end), ((0, 0).into(), (1, 2).into()));
+    /// assert!(grid[1][1]);
+    /// ```
+    ///
+    /// # Errors
+    /// When the grid cannot be parsed, or a marker isn't in it exactly once
+    pub fn parse_marked<E, F, const N: usize>(
+        input: &str,
+        markers: [char; N],
+        mut cell: F,
+    ) -> Result<(Self, [Point; N]), SolveError>
+    where
+        E: Display,
+        F: FnMut(char) -> Result<T, E>,
+    {
+        let mut found = [None; N];
+
+        for (row_index, line) in rows_of(input).enumerate() {
+            for (column_index, c) in line.chars().enumerate() {
+                let Some(marker) = markers.iter().position(|&marker| marker == c) else {
+                    continue;
+                };
+
+                if found[marker].is_some() {
+                    return Err(SolveError::parse(
+                        row_index + 1,
+                        column_index + 1,
+                        format!("expected a single `{}`, found another one", c),
+                    ));
+                }
+
+                found[marker] = Some(Point::new(row_index, column_index));
+            }
+        }
 
-    fn index(&self, index: usize) -> &Self::Output {
-        &self.0[index]
+        let grid = Grid::parse(input, &mut cell)?;
+
+        let mut coordinates = [Point::default(); N];
+
+        for ((coordinates, found), marker) in coordinates.iter_mut().zip(found).zip(markers) {
+            *coordinates = found
+                .ok_or_else(|| SolveError::Invalid(format!("the grid has no `{}`", marker)))?;
+        }
+
+        Ok((grid, coordinates))
     }
-}
 
-impl<T> IndexMut<usize> for Row<T> {
-    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        &mut self.0[index]
+    /// Renders every row on its own, without line breaks.
+    #[must_use]
+    pub fn lines(&self) -> Vec<String>
+    where
+        T: Display,
+    {
+        self.rows()
+            .map(|row| row.iter().map(ToString::to_string).collect())
+            .collect()
     }
-}
 
-impl<T> Deref for Row<T> {
-    type Target = [T];
+    /// Whether `point` lies within the grid.
+    #[must_use]
+    pub const fn contains(&self, point: Point) -> bool {
+        point.row_index < self.row_len && point.column_index < self.column_len
+    }
 
-    #[inline]
-    fn deref(&self) -> &[T] {
-        self.0.as_slice()
+    /// The cell at `point`, `None` when it's out of bounds.
+    #[must_use]
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.contains(point)
+            .then(|| &self.data[point.row_index * self.column_len + point.column_index])
     }
-}
 
-impl<T> DerefMut for Row<T> {
-    fn deref_mut(&mut self) -> &mut [T] {
-        self.0.as_mut_slice()
+    /// The cell at `point` to change it, `None` when it's out of bounds.
+    #[must_use]
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.contains(point)
+            .then(|| &mut self.data[point.row_index * self.column_len + point.column_index])
     }
-}
 
-impl<T> GridIter for Grid<T> {
-    type GridRow = Row<T>;
+    /// The point `vector` away from `point`, `None` when that's out of bounds.
+    #[must_use]
+    pub fn offset(&self, point: Point, vector: Vector) -> Option<Point> {
+        point
+            .checked_add(vector)
+            .filter(|&offset| self.contains(offset))
+    }
 
-    fn get_grid(&self) -> &Vec<Self::GridRow> {
-        &self.data
+    /// The cell `vector` away from `point`, `None` when that's out of bounds.
+    #[must_use]
+    pub fn get_offset(&self, point: Point, vector: Vector) -> Option<&T> {
+        self.get(self.offset(point, vector)?)
     }
 
-    fn get_row_length(&self) -> usize {
-        self.row_len
+    /// The point `vector` away from `point`, wrapping around the edges of the grid.
+    ///
+    /// # Panics
+    /// When the grid is empty, or too large to index with an `isize`
+    #[must_use]
+    pub fn wrapping_offset(&self, point: Point, vector: Vector) -> Point {
+        let wrap = |index: usize, by: isize, length: usize| {
+            let length = isize::try_from(length).expect("Grid too large");
+            let index = isize::try_from(index).expect("Index too large");
+
+            (index + by.rem_euclid(length))
+                .rem_euclid(length)
+                .unsigned_abs()
+        };
+
+        Point::new(
+            wrap(point.row_index, vector.rows, self.row_len),
+            wrap(point.column_index, vector.columns, self.column_len),
+        )
     }
 
-    fn get_column_length(&self) -> usize {
-        self.column_len
+    /// The point `n` steps in `direction` from `point`, `None` when that's out of bounds.
+    #[must_use]
+    pub fn step<D: Into<Vector>>(&self, point: Point, direction: D, n: usize) -> Option<Point> {
+        self.offset(
+            point,
+            direction.into().checked_mul(isize::try_from(n).ok()?)?,
+        )
     }
-}
 
-impl<T> Neighbors for Grid<T> {
-    type Index = usize;
+    /// The neighbor of `point` a single step in `direction`, `None` when that's out of bounds.
+    #[must_use]
+    pub fn neighbor<D: Into<Vector>>(&self, point: Point, direction: D) -> Option<Point> {
+        self.step(point, direction, 1)
+    }
 
-    fn hv_neighbors(
-        &self,
-        row_index: Self::Index,
-        column_index: Self::Index,
-    ) -> HorizontalVerticalNeighbors<Self::Index> {
-        let mut neighbors = vec![];
+    /// The row at `row_index`, `None` when it's out of bounds.
+    #[must_use]
+    pub fn get_row(&self, row_index: usize) -> Option<&[T]> {
+        (row_index < self.row_len).then(|| &self[row_index])
+    }
 
-        let up = row_index.checked_sub(1);
-        let down = {
-            let down = row_index + 1;
+    /// The rows, in order.
+    pub fn rows(&self) -> ChunksExact<'_, T> {
+        self.data.chunks_exact(self.column_len)
+    }
 
-            (down < self.row_len).then_some(down)
-        };
+    /// The rows, in order, to change their cells.
+    pub fn rows_mut(&mut self) -> ChunksExactMut<'_, T> {
+        self.data.chunks_exact_mut(self.column_len)
+    }
 
-        let left = column_index.checked_sub(1);
-        let right = {
-            let right = column_index + 1;
+    /// Every cell, row after row.
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.data.iter()
+    }
 
-            (right < self.column_len).then_some(right)
-        };
+    /// Every cell, row after row, to change it.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        self.data.iter_mut()
+    }
+}
 
-        if let Some(up) = up {
-            neighbors.push(((up, column_index), HorizontalVerticalDirection::Up));
-        }
+impl<'g, T> IntoIterator for &'g Grid<T> {
+    type Item = &'g T;
+    type IntoIter = Iter<'g, T>;
 
-        if let Some(right) = right {
-            neighbors.push(((row_index, right), HorizontalVerticalDirection::Right));
-        }
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
 
-        if let Some(down) = down {
-            neighbors.push(((down, column_index), HorizontalVerticalDirection::Down));
-        }
+impl<'g, T> IntoIterator for &'g mut Grid<T> {
+    type Item = &'g mut T;
+    type IntoIter = IterMut<'g, T>;
 
-        if let Some(left) = left {
-            neighbors.push(((row_index, left), HorizontalVerticalDirection::Left));
-        }
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
 
-        neighbors
+/// The lines of a grid in `input`, without the blank lines at the end.
+fn rows_of(input: &str) -> impl Iterator<Item = &str> {
+    let mut lines = input.lines().collect::<Vec<_>>();
+
+    while lines.last().is_some_and(|line| line.trim().is_empty()) {
+        lines.pop();
     }
 
-    fn hvd_neighbors(
-        &self,
-        row_index: Self::Index,
-        column_index: Self::Index,
-    ) -> HorizontalVerticalDiagonalNeighbors<Self::Index> {
-        let mut neighbors = vec![];
+    lines.into_iter()
+}
 
-        let up = row_index.checked_sub(1);
-        let down = {
-            let down = row_index + 1;
+/// Parses a grid whose cells are [`TryFrom<char>`], see [`Grid::parse`].
+impl<T> FromStr for Grid<T>
+where
+    T: TryFrom<char>,
+    T::Error: Display,
+{
+    type Err = SolveError;
 
-            (down < self.row_len).then_some(down)
-        };
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::parse(s, T::try_from)
+    }
+}
 
-        let left = column_index.checked_sub(1);
-        let right = {
-            let right = column_index + 1;
+impl<T> GridIter for Grid<T> {
+    type Cell = T;
 
-            (right < self.column_len).then_some(right)
-        };
+    fn get_row_length(&self) -> usize {
+        self.row_len
+    }
 
-        if let Some(up) = up {
-            neighbors.push(((up, column_index), HorizontalVerticalDiagonalDirection::Up));
-        }
+    fn get_column_length(&self) -> usize {
+        self.column_len
+    }
 
-        if let (Some(up), Some(right)) = (up, right) {
-            neighbors.push(((up, right), HorizontalVerticalDiagonalDirection::UpRight));
-        }
+    fn row(&self, row_index: usize) -> &[T] {
+        &self[row_index]
+    }
+}
 
-        if let Some(right) = right {
-            neighbors.push((
-                (row_index, right),
-                HorizontalVerticalDiagonalDirection::Right,
-            ));
-        }
+impl<T> Grid<T> {
+    /// The rows above and below and the columns left and right of a cell, `None` past the edges.
+    fn adjacent_indices(
+        &self,
+        row_index: usize,
+        column_index: usize,
+    ) -> (Option<usize>, Option<usize>, Option<usize>, Option<usize>) {
+        let up = row_index.checked_sub(1);
+        let down = Some(row_index + 1).filter(|&down| down < self.row_len);
 
-        if let (Some(down), Some(right)) = (down, right) {
-            neighbors.push((
-                (down, right),
-                HorizontalVerticalDiagonalDirection::DownRight,
-            ));
-        }
+        let left = column_index.checked_sub(1);
+        let right = Some(column_index + 1).filter(|&right| right < self.column_len);
 
-        if let Some(down) = down {
-            neighbors.push((
-                (down, column_index),
-                HorizontalVerticalDiagonalDirection::Down,
-            ));
-        }
+        (up, right, down, left)
+    }
+}
 
-        if let (Some(down), Some(left)) = (down, left) {
-            neighbors.push(((down, left), HorizontalVerticalDiagonalDirection::DownLeft));
-        }
+impl<T> Neighbors for Grid<T> {
+    type Index = usize;
 
-        if let Some(left) = left {
-            neighbors.push(((row_index, left), HorizontalVerticalDiagonalDirection::Left));
-        }
+    fn cell(&self, row_index: Self::Index, column_index: Self::Index) -> &T {
+        &self[(row_index, column_index)]
+    }
 
-        if let (Some(up), Some(left)) = (up, left) {
-            neighbors.push(((up, left), HorizontalVerticalDiagonalDirection::UpLeft));
-        }
+    fn hv_neighbors(
+        &self,
+        row_index: Self::Index,
+        column_index: Self::Index,
+    ) -> impl Iterator<Item = HorizontalVerticalNeighbor<Self::Index>> {
+        let (up, right, down, left) = self.adjacent_indices(row_index, column_index);
+
+        [
+            up.map(|up| ((up, column_index), HorizontalVerticalDirection::Up)),
+            right.map(|right| ((row_index, right), HorizontalVerticalDirection::Right)),
+            down.map(|down| ((down, column_index), HorizontalVerticalDirection::Down)),
+            left.map(|left| ((row_index, left), HorizontalVerticalDirection::Left)),
+        ]
+        .into_iter()
+        .flatten()
+    }
 
-        neighbors
+    fn hvd_neighbors(
+        &self,
+        row_index: Self::Index,
+        column_index: Self::Index,
+    ) -> impl Iterator<Item = HorizontalVerticalDiagonalNeighbor<Self::Index>> {
+        let (up, right, down, left) = self.adjacent_indices(row_index, column_index);
+
+        [
+            up.map(|up| ((up, column_index), HorizontalVerticalDiagonalDirection::Up)),
+            up.zip(right)
+                .map(|up_right| (up_right, HorizontalVerticalDiagonalDirection::UpRight)),
+            right.map(|right| {
+                (
+                    (row_index, right),
+                    HorizontalVerticalDiagonalDirection::Right,
+                )
+            }),
+            down.zip(right)
+                .map(|down_right| (down_right, HorizontalVerticalDiagonalDirection::DownRight)),
+            down.map(|down| {
+                (
+                    (down, column_index),
+                    HorizontalVerticalDiagonalDirection::Down,
+                )
+            }),
+            down.zip(left)
+                .map(|down_left| (down_left, HorizontalVerticalDiagonalDirection::DownLeft)),
+            left.map(|left| ((row_index, left), HorizontalVerticalDiagonalDirection::Left)),
+            up.zip(left)
+                .map(|up_left| (up_left, HorizontalVerticalDiagonalDirection::UpLeft)),
+        ]
+        .into_iter()
+        .flatten()
     }
 }
 
@@ -229,8 +429,8 @@ where
     T: std::fmt::Display,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for row in &self.data {
-            for t in &row.0 {
+        for row in self.rows() {
+            for t in row {
                 write!(f, "{}", t)?;
             }
 
@@ -247,8 +447,8 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         writeln!(f, "Rows: {}, Columns: {}", self.row_len, self.column_len)?;
-        for row in &self.data {
-            for t in &row.0 {
+        for row in self.rows() {
+            for t in row {
                 write!(f, "{:?}", t)?;
             }
 
@@ -259,28 +459,167 @@ where
     }
 }
 
+/// The row at the index.
 impl<T> Index<usize> for Grid<T> {
-    type Output = Row<T>;
+    type Output = [T];
 
     fn index(&self, index: usize) -> &Self::Output {
-        &self.data[index]
+        assert!(index < self.row_len, "Row index out of bounds");
+
+        &self.data[index * self.column_len..(index + 1) * self.column_len]
     }
 }
 
 impl<T> IndexMut<usize> for Grid<T> {
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        &mut self.data[index]
+        assert!(index < self.row_len, "Row index out of bounds");
+
+        &mut self.data[index * self.column_len..(index + 1) * self.column_len]
+    }
+}
+
+/// The cell at the row and column index.
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row_index, column_index): (usize, usize)) -> &Self::Output {
+        assert!(column_index < self.column_len, "Column index out of bounds");
+
+        &self[row_index][column_index]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row_index, column_index): (usize, usize)) -> &mut Self::Output {
+        assert!(column_index < self.column_len, "Column index out of bounds");
+
+        &mut self[row_index][column_index]
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, index: Point) -> &Self::Output {
+        &self[(index.row_index, index.column_index)]
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, index: Point) -> &mut Self::Output {
+        &mut self[(index.row_index, index.column_index)]
+    }
+}
+
+/// Serializes as its [lines](Grid::lines).
+#[cfg(feature = "serde")]
+impl Serialize for Grid<char> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.lines().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Grid<char> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let rows = Vec::<String>::deserialize(deserializer)?
+            .into_iter()
+            .map(|line| line.chars().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        if rows.is_empty() {
+            return Err(D::Error::custom("expected at least one row"));
+        }
+
+        if rows[0].is_empty() {
+            return Err(D::Error::custom("expected rows of at least one cell"));
+        }
+
+        if rows.windows(2).any(|w| w[0].len() != w[1].len()) {
+            return Err(D::Error::custom("expected rows of equal length"));
+        }
+
+        Ok(Grid::new(rows))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::Grid;
+    use crate::shared::error::SolveError;
+    use crate::shared::grids::point::{Point, Vector};
     use crate::shared::grids::{
         GridIter as _, HorizontalVerticalDiagonalDirection, HorizontalVerticalDirection,
         Neighbors as _,
     };
 
+    fn digit(c: char) -> Result<u32, &'static str> {
+        c.to_digit(10).ok_or("expected a digit")
+    }
+
+    #[test]
+    fn parse() {
+        let g = Grid::parse("123\n456\n\n  \n", digit).unwrap();
+
+        assert_eq!((2, 3), (g.get_row_length(), g.get_column_length()));
+        assert_eq!(6, g[1][2]);
+
+        let g: Grid<char> = "ab\ncd".parse().unwrap();
+
+        assert_eq!(vec!["ab", "cd"], g.lines());
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            Err(SolveError::parse(2, 3, "expected a digit, found `x`")),
+            Grid::parse("123\n45x", digit)
+        );
+        assert_eq!(
+            Err(SolveError::parse(
+                2,
+                3,
+                "expected a row of 3 cells like the first one, found 2"
+            )),
+            Grid::parse("123\n45\n789", digit)
+        );
+        assert_eq!(
+            Err(SolveError::parse(
+                2,
+                1,
+                "expected a row of 3 cells like the first one, found 0"
+            )),
+            Grid::parse("123\n\n456", digit)
+        );
+        assert_eq!(
+            Err(SolveError::Invalid("the grid is empty".into())),
+            Grid::parse("\n\n", digit)
+        );
+    }
+
+    #[test]
+    fn parse_marked() {
+        let parse = |input| Grid::parse_marked(input, ['S', 'E'], |c| Ok::<_, &str>(c == '#'));
+
+        let (g, markers) = parse("S.#\n#.E\n").unwrap();
+
+        assert_eq!([Point::new(0, 0), Point::new(1, 2)], markers);
+        assert!(g[0][2] && !g[0][0]);
+
+        assert_eq!(
+            Err(SolveError::Invalid("the grid has no `E`".into())),
+            parse("S.#\n#..")
+        );
+        assert_eq!(
+            Err(SolveError::parse(
+                2,
+                2,
+                "expected a single `S`, found another one"
+            )),
+            parse("S.#\n#SE")
+        );
+    }
+
     #[test]
     fn rows() {
         let g = Grid::new(vec![
@@ -289,9 +628,120 @@ mod tests {
             vec!['g', 'h', 'i'],
         ]);
 
-        let rows = g.data.iter().collect::<Vec<_>>();
+        let rows = vec![['a', 'b', 'c'], ['d', 'e', 'f'], ['g', 'h', 'i']];
 
         assert_eq!(rows, g.row_iter().collect::<Vec<_>>());
+        assert_eq!(rows, g.rows().collect::<Vec<_>>());
+        assert_eq!(&rows[1], &g[1]);
+    }
+
+    #[test]
+    fn indexing() {
+        let mut g = Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+
+        g[(1, 0)] = 40;
+        g[0][2] = 30;
+
+        for row in g.rows_mut() {
+            row[1] *= 10;
+        }
+
+        assert_eq!(40, g[1][0]);
+        assert_eq!(30, g[(0, 2)]);
+        assert_eq!(
+            vec![&1, &20, &30, &40, &50, &6],
+            g.iter().collect::<Vec<_>>()
+        );
+        assert_eq!(None, g.get_row(2));
+    }
+
+    #[test]
+    fn points() {
+        let mut g = Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+
+        g[Point::new(1, 1)] = 50;
+
+        assert_eq!(Some(&50), g.get(Point::new(1, 1)));
+        assert_eq!(None, g.get(Point::new(0, 3)));
+        assert_eq!(None, g.get(Point::new(2, 0)));
+        assert_eq!(
+            Some(Point::new(0, 2)),
+            g.neighbor(
+                Point::new(1, 1),
+                HorizontalVerticalDiagonalDirection::UpRight
+            )
+        );
+        assert_eq!(
+            None,
+            g.neighbor(Point::new(1, 1), HorizontalVerticalDirection::Down)
+        );
+    }
+
+    #[test]
+    fn checked_access() {
+        let mut g = Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+
+        if let Some(cell) = g.get_mut(Point::new(0, 2)) {
+            *cell = 30;
+        }
+
+        assert_eq!(None, g.get_mut(Point::new(2, 2)));
+        assert!(g.contains(Point::new(1, 2)));
+        assert!(!g.contains(Point::new(1, 3)));
+        assert_eq!(
+            Some(&30),
+            g.get_offset(Point::new(1, 0), Vector::new(-1, 2))
+        );
+        assert_eq!(None, g.get_offset(Point::new(1, 0), Vector::new(0, -1)));
+        assert_eq!(
+            Some(Point::new(1, 2)),
+            g.step(Point::new(1, 0), HorizontalVerticalDirection::Right, 2)
+        );
+        assert_eq!(
+            None,
+            g.step(Point::new(1, 0), HorizontalVerticalDirection::Right, 3)
+        );
+        assert_eq!(
+            None,
+            g.step(
+                Point::new(1, 0),
+                HorizontalVerticalDirection::Up,
+                usize::MAX
+            )
+        );
+        assert_eq!(
+            None,
+            g.step(
+                Point::new(1, 0),
+                Vector::new(0, 2),
+                isize::MAX.unsigned_abs()
+            )
+        );
+        assert_eq!(
+            Point::new(0, 1),
+            g.wrapping_offset(Point::new(1, 2), Vector::new(1, -7))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Grid has no cells")]
+    fn empty_rows() {
+        let _grid: Grid<char> = Grid::new(vec![vec![], vec![]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Row length differs")]
+    fn ragged_rows() {
+        let _grid = Grid::new(vec![vec![1, 2], vec![3]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Column index out of bounds")]
+    fn index_out_of_bounds() {
+        let g = Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+
+        // would be the first cell of the second row in the flat storage
+        assert_eq!(4, g[(0, 3)]);
     }
 
     #[test]
@@ -351,7 +801,7 @@ mod tests {
             ((1, 0), HorizontalVerticalDirection::Left),
         ];
 
-        assert_eq!(v, g.hv_neighbors(1, 1));
+        assert_eq!(v, g.hv_neighbors(1, 1).collect::<Vec<_>>());
     }
 
     #[test]
@@ -367,7 +817,7 @@ mod tests {
             ((1, 0), HorizontalVerticalDirection::Down),
         ];
 
-        assert_eq!(v, g.hv_neighbors(0, 0));
+        assert_eq!(v, g.hv_neighbors(0, 0).collect::<Vec<_>>());
     }
 
     #[test]
@@ -389,7 +839,7 @@ mod tests {
             ((0, 0), HorizontalVerticalDiagonalDirection::UpLeft),
         ];
 
-        assert_eq!(v, g.hvd_neighbors(1, 1));
+        assert_eq!(v, g.hvd_neighbors(1, 1).collect::<Vec<_>>());
     }
 
     #[test]
@@ -406,6 +856,40 @@ mod tests {
             ((1, 0), HorizontalVerticalDiagonalDirection::Down),
         ];
 
-        assert_eq!(v, g.hvd_neighbors(0, 0));
+        assert_eq!(v, g.hvd_neighbors(0, 0).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn neighbors_where() {
+        let g = Grid::new(vec![
+            vec!['a', 'B', 'c'],
+            vec!['D', 'e', 'F'],
+            vec!['g', 'H', 'i'],
+        ]);
+
+        let v = vec![
+            ((0, 1), HorizontalVerticalDirection::Up),
+            ((1, 2), HorizontalVerticalDirection::Right),
+            ((2, 1), HorizontalVerticalDirection::Down),
+            ((1, 0), HorizontalVerticalDirection::Left),
+        ];
+
+        assert_eq!(
+            v,
+            g.hv_neighbors_where(1, 1, char::is_ascii_uppercase)
+                .collect::<Vec<_>>()
+        );
+
+        let v = vec![
+            ((0, 2), HorizontalVerticalDiagonalDirection::Right),
+            ((1, 1), HorizontalVerticalDiagonalDirection::Down),
+            ((0, 0), HorizontalVerticalDiagonalDirection::Left),
+        ];
+
+        assert_eq!(
+            v,
+            g.hvd_neighbors_where(0, 1, char::is_ascii_lowercase)
+                .collect::<Vec<_>>()
+        );
     }
 }
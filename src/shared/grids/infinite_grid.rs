@@ -1,7 +1,8 @@
 use std::ops::{Deref, DerefMut, Index};
 
 use super::{
-    GridIter, HorizontalVerticalDiagonalDirection, HorizontalVerticalDirection, Neighbors,
+    GridIter, HorizontalVerticalDiagonalDirection, HorizontalVerticalDiagonalNeighbor,
+    HorizontalVerticalDirection, HorizontalVerticalNeighbor, Neighbors,
 };
 
 #[derive(PartialEq, Eq, PartialOrd, Ord)]
@@ -106,11 +107,7 @@ impl<T> InfiniteGrid<T> {
 }
 
 impl<T> GridIter for InfiniteGrid<T> {
-    type GridRow = InfiniteRow<T>;
-
-    fn get_grid(&self) -> &Vec<Self::GridRow> {
-        &self.data
-    }
+    type Cell = T;
 
     fn get_row_length(&self) -> usize {
         self.row_len
@@ -119,17 +116,25 @@ impl<T> GridIter for InfiniteGrid<T> {
     fn get_column_length(&self) -> usize {
         self.column_len
     }
+
+    fn row(&self, row_index: usize) -> &[T] {
+        &self.data[row_index]
+    }
 }
 
 impl<T> Neighbors for InfiniteGrid<T> {
     type Index = isize;
 
+    fn cell(&self, row_index: Self::Index, column_index: Self::Index) -> &T {
+        &self[row_index][column_index]
+    }
+
     fn hv_neighbors(
         &self,
         row_index: Self::Index,
         column_index: Self::Index,
-    ) -> Vec<((Self::Index, Self::Index), HorizontalVerticalDirection)> {
-        vec![
+    ) -> impl Iterator<Item = HorizontalVerticalNeighbor<Self::Index>> {
+        [
             (
                 (row_index - 1, column_index),
                 HorizontalVerticalDirection::Up,
@@ -147,17 +152,15 @@ impl<T> Neighbors for InfiniteGrid<T> {
                 HorizontalVerticalDirection::Left,
             ),
         ]
+        .into_iter()
     }
 
     fn hvd_neighbors(
         &self,
         row_index: Self::Index,
         column_index: Self::Index,
-    ) -> Vec<(
-        (Self::Index, Self::Index),
-        HorizontalVerticalDiagonalDirection,
-    )> {
-        vec![
+    ) -> impl Iterator<Item = HorizontalVerticalDiagonalNeighbor<Self::Index>> {
+        [
             (
                 (row_index - 1, column_index),
                 HorizontalVerticalDiagonalDirection::Up,
@@ -191,6 +194,7 @@ impl<T> Neighbors for InfiniteGrid<T> {
                 HorizontalVerticalDiagonalDirection::UpLeft,
             ),
         ]
+        .into_iter()
     }
 }
 
@@ -286,7 +290,7 @@ mod tests {
             ((1, 0), HorizontalVerticalDirection::Left),
         ];
 
-        assert_eq!(v, g.hv_neighbors(1, 1));
+        assert_eq!(v, g.hv_neighbors(1, 1).collect::<Vec<_>>());
     }
 
     #[test]
@@ -304,7 +308,7 @@ mod tests {
             ((0, -1), HorizontalVerticalDirection::Left),
         ];
 
-        assert_eq!(v, g.hv_neighbors(0, 0));
+        assert_eq!(v, g.hv_neighbors(0, 0).collect::<Vec<_>>());
     }
 
     #[test]
@@ -326,7 +330,7 @@ mod tests {
             ((0, 0), HorizontalVerticalDiagonalDirection::UpLeft),
         ];
 
-        assert_eq!(v, g.hvd_neighbors(1, 1));
+        assert_eq!(v, g.hvd_neighbors(1, 1).collect::<Vec<_>>());
     }
 
     #[test]
@@ -348,6 +352,6 @@ mod tests {
             ((-1, -1), HorizontalVerticalDiagonalDirection::UpLeft),
         ];
 
-        assert_eq!(v, g.hvd_neighbors(0, 0));
+        assert_eq!(v, g.hvd_neighbors(0, 0).collect::<Vec<_>>());
     }
 }
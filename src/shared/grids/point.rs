@@ -0,0 +1,193 @@
+use std::fmt::Display;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// The position of a cell in a [`Grid`](super::grid::Grid), by row and then column.
+///
+/// # Display
+/// This value displays as `(row, column)`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub row_index: usize,
+    pub column_index: usize,
+}
+
+impl Point {
+    #[must_use]
+    pub const fn new(row_index: usize, column_index: usize) -> Self {
+        Point {
+            row_index,
+            column_index,
+        }
+    }
+
+    /// The point `vector` away, `None` when that is above or left of the grid's origin.
+    #[must_use]
+    pub fn checked_add(self, vector: Vector) -> Option<Point> {
+        Some(Point {
+            row_index: self.row_index.checked_add_signed(vector.rows)?,
+            column_index: self.column_index.checked_add_signed(vector.columns)?,
+        })
+    }
+
+    /// The vector from `other` to this point, `None` when it doesn't fit an `isize`.
+    #[must_use]
+    pub fn checked_sub(self, other: Point) -> Option<Vector> {
+        Some(Vector {
+            rows: self.row_index.checked_signed_diff(other.row_index)?,
+            columns: self.column_index.checked_signed_diff(other.column_index)?,
+        })
+    }
+
+    /// The number of horizontal and vertical steps to `other`.
+    #[must_use]
+    pub const fn manhattan(self, other: Point) -> usize {
+        self.row_index.abs_diff(other.row_index) + self.column_index.abs_diff(other.column_index)
+    }
+
+    /// The number of steps to `other` when diagonal steps are allowed too.
+    #[must_use]
+    pub fn chebyshev(self, other: Point) -> usize {
+        self.row_index
+            .abs_diff(other.row_index)
+            .max(self.column_index.abs_diff(other.column_index))
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((row_index, column_index): (usize, usize)) -> Self {
+        Point::new(row_index, column_index)
+    }
+}
+
+impl From<Point> for (usize, usize) {
+    fn from(point: Point) -> Self {
+        (point.row_index, point.column_index)
+    }
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.row_index, self.column_index)
+    }
+}
+
+/// A move between two [`Point`]s, in rows down and columns right.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vector {
+    pub rows: isize,
+    pub columns: isize,
+}
+
+impl Vector {
+    #[must_use]
+    pub const fn new(rows: isize, columns: isize) -> Self {
+        Vector { rows, columns }
+    }
+
+    /// This vector `n` times over, `None` when that overflows.
+    #[must_use]
+    pub const fn checked_mul(self, n: isize) -> Option<Vector> {
+        match (self.rows.checked_mul(n), self.columns.checked_mul(n)) {
+            (Some(rows), Some(columns)) => Some(Vector::new(rows, columns)),
+            _ => None,
+        }
+    }
+
+    /// The number of horizontal and vertical steps this vector takes.
+    #[must_use]
+    pub const fn manhattan(self) -> usize {
+        self.rows.unsigned_abs() + self.columns.unsigned_abs()
+    }
+
+    /// The number of steps this vector takes when diagonal steps are allowed too.
+    #[must_use]
+    pub fn chebyshev(self) -> usize {
+        self.rows.unsigned_abs().max(self.columns.unsigned_abs())
+    }
+}
+
+impl From<(isize, isize)> for Vector {
+    fn from((rows, columns): (isize, isize)) -> Self {
+        Vector::new(rows, columns)
+    }
+}
+
+impl Add for Vector {
+    type Output = Vector;
+
+    fn add(self, rhs: Vector) -> Self::Output {
+        Vector::new(self.rows + rhs.rows, self.columns + rhs.columns)
+    }
+}
+
+impl AddAssign for Vector {
+    fn add_assign(&mut self, rhs: Vector) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Vector {
+    type Output = Vector;
+
+    fn sub(self, rhs: Vector) -> Self::Output {
+        Vector::new(self.rows - rhs.rows, self.columns - rhs.columns)
+    }
+}
+
+impl SubAssign for Vector {
+    fn sub_assign(&mut self, rhs: Vector) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<isize> for Vector {
+    type Output = Vector;
+
+    fn mul(self, rhs: isize) -> Self::Output {
+        Vector::new(self.rows * rhs, self.columns * rhs)
+    }
+}
+
+impl Neg for Vector {
+    type Output = Vector;
+
+    fn neg(self) -> Self::Output {
+        Vector::new(-self.rows, -self.columns)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Point, Vector};
+
+    #[test]
+    fn arithmetic() {
+        let point = Point::new(3, 5);
+
+        assert_eq!(
+            Some(Point::new(1, 7)),
+            point.checked_add(Vector::new(-2, 2))
+        );
+        assert_eq!(None, point.checked_add(Vector::new(0, -6)));
+        assert_eq!(
+            Some(Vector::new(-2, 2)),
+            Point::new(1, 7).checked_sub(point)
+        );
+        assert_eq!(
+            Vector::new(-2, 4),
+            -(Vector::new(1, 1) - Vector::new(0, 3)) * 2
+        );
+        assert_eq!(Some(Vector::new(-4, 6)), Vector::new(-2, 3).checked_mul(2));
+        assert_eq!(None, Vector::new(0, 2).checked_mul(isize::MAX));
+    }
+
+    #[test]
+    fn distances() {
+        let (from, to) = (Point::new(3, 5), Point::new(1, 9));
+
+        assert_eq!(6, from.manhattan(to));
+        assert_eq!(4, from.chebyshev(to));
+        assert_eq!(6, from.checked_sub(to).unwrap().manhattan());
+        assert_eq!(4, Vector::new(-4, 1).chebyshev());
+    }
+}
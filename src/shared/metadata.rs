@@ -0,0 +1,105 @@
+use std::fmt::Display;
+
+use super::tag::Tag;
+
+/// How far along a solution is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// Both parts are solved.
+    Solved,
+    /// Only one of the parts is solved.
+    Partial,
+    /// There is a solution, but neither part is solved.
+    Unsolved,
+}
+
+impl Status {
+    /// The status of a solution with the answers of `known` of its parts known.
+    #[must_use]
+    pub const fn from_known(known: usize) -> Status {
+        match known {
+            0 => Status::Unsolved,
+            1 => Status::Partial,
+            _ => Status::Solved,
+        }
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad(match *self {
+            Status::Solved => "solved",
+            Status::Partial => "partial",
+            Status::Unsolved => "unsolved",
+        })
+    }
+}
+
+/// What a solution tells about itself, declared in [`solution!`](crate::solution).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Metadata {
+    /// The title of the puzzle.
+    pub title: Option<&'static str>,
+    pub tags: &'static [Tag],
+    /// How the running time grows with the input, like `O(n log n)`.
+    pub complexity: Option<&'static str>,
+    /// The modules of [`shared`](crate::shared) the solution uses, like `grids`.
+    pub uses: &'static [&'static str],
+    /// How far along the solution is, when it says so. Otherwise it follows from the known
+    /// answers, see [`Status::from_known`].
+    pub status: Option<Status>,
+}
+
+impl Metadata {
+    /// The metadata of a solution that doesn't declare any.
+    pub const DEFAULT: Metadata = Metadata {
+        title: None,
+        tags: &[],
+        complexity: None,
+        uses: &[],
+        status: None,
+    };
+}
+
+impl Default for Metadata {
+    fn default() -> Self {
+        Metadata::DEFAULT
+    }
+}
+
+/// A solution that can tell about itself, see [`Metadata`].
+pub trait Describe {
+    fn metadata(&self) -> &'static Metadata;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Describe as _, Metadata, Status};
+    use crate::days::{day_01, day_18};
+    use crate::shared::tag::Tag;
+
+    #[test]
+    fn declared() {
+        assert_eq!(
+            Metadata {
+                title: Some("RAM Run"),
+                tags: &[Tag::Grids, Tag::Pathfinding],
+                complexity: None,
+                uses: &["grids", "params"],
+                status: None,
+            },
+            *day_18::Solution {}.metadata()
+        );
+        assert_eq!(
+            Some("O(n log n)"),
+            day_01::Solution {}.metadata().complexity
+        );
+    }
+
+    #[test]
+    fn status() {
+        assert_eq!(Status::Unsolved, Status::from_known(0));
+        assert_eq!(Status::Partial, Status::from_known(1));
+        assert_eq!(Status::Solved, Status::from_known(2));
+    }
+}
@@ -0,0 +1,139 @@
+use super::grids::GridIter as _;
+use super::grids::grid::Grid;
+
+/// The height of a letter, in cells.
+const HEIGHT: usize = 6;
+
+/// The width of a letter, in cells. Letters are separated by an unlit column.
+const WIDTH: usize = 4;
+
+/// The block letters the puzzles draw their answers in, as far as they are known. Lit cells are
+/// `#`.
+const FONT: [(char, [&str; HEIGHT]); 17] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+fn is_lit(cell: char) -> bool {
+    matches!(cell, '#' | '\u{2588}')
+}
+
+/// Reads the block letters drawn in `grid`, with `#` or `█` for lit cells and anything else for
+/// unlit ones.
+///
+/// Returns `None` unless `grid` is exactly one line of known letters, optionally followed by an
+/// unlit column.
+#[must_use]
+pub fn recognise(grid: &Grid<char>) -> Option<String> {
+    if grid.get_row_length() != HEIGHT {
+        return None;
+    }
+
+    let width = grid.get_column_length();
+
+    // every letter but the last is followed by a separating column
+    if width == 0 || (width + 1) % (WIDTH + 1) > 1 {
+        return None;
+    }
+
+    let mut text = String::new();
+
+    for start in (0..width).step_by(WIDTH + 1) {
+        if start + WIDTH < width && grid.rows().any(|row| is_lit(row[start + WIDTH])) {
+            return None;
+        }
+
+        let &(letter, _) = FONT.iter().find(|&&(_, ref rows)| {
+            rows.iter().zip(grid.rows()).all(|(font_row, row)| {
+                font_row
+                    .chars()
+                    .zip(row.iter().skip(start))
+                    .all(|(font_cell, &cell)| (font_cell == '#') == is_lit(cell))
+            })
+        })?;
+
+        text.push(letter);
+    }
+
+    Some(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::recognise;
+    use crate::shared::grids::grid::Grid;
+
+    fn parse(picture: &str) -> Grid<char> {
+        Grid::new(picture.lines().map(|line| line.chars().collect()).collect())
+    }
+
+    #[test]
+    fn letters() {
+        let picture = parse(
+            "\
+####.#..#.####.###..
+#....#..#....#.#..#.
+###..####...#..#..#.
+#....#..#..#...###..
+#....#..#.#....#.#..
+####.#..#.####.#..#.",
+        );
+
+        assert_eq!(Some("EHZR".into()), recognise(&picture));
+    }
+
+    #[test]
+    fn blocks() {
+        let picture = parse(
+            &"\
+.##..###.
+#..#.#..#
+#..#.###.
+####.#..#
+#..#.#..#
+#..#.###."
+                .replace('#', "\u{2588}")
+                .replace('.', " "),
+        );
+
+        assert_eq!(Some("AB".into()), recognise(&picture));
+    }
+
+    #[test]
+    fn unknown() {
+        // not a letter
+        assert_eq!(
+            None,
+            recognise(&parse("####\n####\n####\n####\n####\n####"))
+        );
+        // lit separating column
+        assert_eq!(
+            None,
+            recognise(&parse(
+                "#...##...\n#...##...\n#...##...\n#...##...\n#...##...\n#######.."
+            ))
+        );
+        // too short
+        assert_eq!(None, recognise(&parse("#...\n#...\n####")));
+        // not a whole number of letters
+        assert_eq!(
+            None,
+            recognise(&parse("#...#.\n#...#.\n#...#.\n#...#.\n#...#.\n####..")),
+        );
+    }
+}
@@ -0,0 +1,227 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// The parameters of a puzzle that aren't part of the input, like the size of a grid. The
+/// defaults are those of the actual puzzle, examples tend to use smaller ones.
+///
+/// Implemented for a day's `Params` by [`params!`](crate::params), and for `()` for the days
+/// without any.
+pub trait Params: Default {
+    /// The names of the parameters, as accepted by [`Params::set`].
+    const NAMES: &'static [&'static str];
+
+    /// Overrides the parameter called `name` with `value`.
+    ///
+    /// # Errors
+    /// When there is no such parameter, or `value` isn't valid for it
+    fn set(&mut self, name: &str, value: &str) -> Result<(), ParamError>;
+
+    /// The defaults, with `overrides` applied in order.
+    ///
+    /// # Errors
+    /// When one of the overrides cannot be applied
+    fn with(overrides: &[Param]) -> Result<Self, ParamError> {
+        let mut params = Self::default();
+
+        for param in overrides {
+            params.set(&param.name, &param.value)?;
+        }
+
+        Ok(params)
+    }
+}
+
+impl Params for () {
+    const NAMES: &'static [&'static str] = &[];
+
+    fn set(&mut self, name: &str, _value: &str) -> Result<(), ParamError> {
+        Err(ParamError::Unknown {
+            name: name.to_owned(),
+            known: Self::NAMES,
+        })
+    }
+}
+
+/// An untyped override of a parameter, written as `name=value`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Param {
+    pub name: String,
+    pub value: String,
+}
+
+impl FromStr for Param {
+    type Err = ParamError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once('=') {
+            Some((name, value)) if !name.is_empty() => Ok(Param {
+                name: name.to_owned(),
+                value: value.to_owned(),
+            }),
+            _ => Err(ParamError::Malformed(s.to_owned())),
+        }
+    }
+}
+
+impl Display for Param {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}={}", self.name, self.value)
+    }
+}
+
+/// An error which can be returned when overriding [`Params`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParamError {
+    Malformed(String),
+    Unknown {
+        name: String,
+        known: &'static [&'static str],
+    },
+    Invalid {
+        name: String,
+        value: String,
+    },
+}
+
+impl Error for ParamError {}
+
+impl Display for ParamError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            ParamError::Malformed(ref param) => {
+                write!(f, "invalid parameter `{}`, expecting `NAME=VALUE`", param)
+            },
+            ParamError::Unknown {
+                ref name,
+                known: &[],
+            } => {
+                write!(
+                    f,
+                    "unknown parameter `{}`, this day has no parameters",
+                    name
+                )
+            },
+            ParamError::Unknown { ref name, known } => write!(
+                f,
+                "unknown parameter `{}`, expecting one of `{}`",
+                name,
+                known.join("`, `")
+            ),
+            ParamError::Invalid {
+                ref name,
+                ref value,
+            } => write!(f, "invalid value `{}` for parameter `{}`", value, name),
+        }
+    }
+}
+
+/// Declares the `Params` of the solution in the invoking `day_NN.rs` file, with a default per
+/// parameter, to use as [`TryParts::Params`](crate::shared::TryParts::Params).
+///
+/// ```ignore
+/// crate::params! {
+///     /// The width of the room.
+///     width: isize = 101,
+///     /// The height of the room.
+///     height: isize = 103,
+/// }
+/// ```
+#[macro_export]
+macro_rules! params {
+    ($($(#[$meta:meta])* $name:ident: $type:ty = $default:expr),* $(,)?) => {
+        /// The parameters of the puzzle, defaulting to those of the actual puzzle.
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        pub struct Params {
+            $($(#[$meta])* pub $name: $type,)*
+        }
+
+        impl Default for Params {
+            fn default() -> Self {
+                Self {
+                    $($name: $default,)*
+                }
+            }
+        }
+
+        impl $crate::shared::params::Params for Params {
+            const NAMES: &'static [&'static str] = &[$(stringify!($name)),*];
+
+            fn set(
+                &mut self,
+                name: &str,
+                value: &str,
+            ) -> Result<(), $crate::shared::params::ParamError> {
+                let invalid = || $crate::shared::params::ParamError::Invalid {
+                    name: name.to_owned(),
+                    value: value.to_owned(),
+                };
+
+                match name {
+                    $(stringify!($name) => self.$name = value.parse().map_err(|_| invalid())?,)*
+                    _ => {
+                        return Err($crate::shared::params::ParamError::Unknown {
+                            name: name.to_owned(),
+                            known: Self::NAMES,
+                        });
+                    },
+                }
+
+                Ok(())
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Param, ParamError, Params as _};
+
+    crate::params! {
+        /// A size.
+        width: usize = 101,
+        minimum: i32 = -3,
+    }
+
+    #[test]
+    fn overrides() {
+        let params = Params::with(&["width=11".parse().unwrap()]).unwrap();
+
+        assert_eq!(
+            Params {
+                width: 11,
+                minimum: -3
+            },
+            params
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            Err(ParamError::Malformed("width".into())),
+            "width".parse::<Param>()
+        );
+        assert_eq!(
+            Err(ParamError::Unknown {
+                name: "height".into(),
+                known: &["width", "minimum"]
+            }),
+            Params::with(&["height=7".parse().unwrap()])
+        );
+        assert_eq!(
+            Err(ParamError::Invalid {
+                name: "width".into(),
+                value: "-1".into()
+            }),
+            Params::with(&["width=-1".parse().unwrap()])
+        );
+        assert_eq!(
+            Err(ParamError::Unknown {
+                name: "width".into(),
+                known: &[]
+            }),
+            <()>::with(&["width=1".parse().unwrap()])
+        );
+    }
+}
@@ -0,0 +1,126 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[cfg(feature = "serde")]
+use serde::de::Error as _;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::day::Day;
+use super::year::Year;
+
+/// A day of a specific event, which the event has: the 12th day of 2025 is the last one.
+///
+/// # Display
+/// This value displays as the year and the two digit day, separated by a `/`.
+///
+/// ```
+/// # use advent_of_code_2024::shared::puzzle::PuzzleId;
+/// let puzzle: PuzzleId = "2024/7".parse().unwrap();
+/// assert_eq!(puzzle.to_string(), "2024/07");
+/// assert!("2025/13".parse::<PuzzleId>().is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PuzzleId {
+    year: Year,
+    day: Day,
+}
+
+impl PuzzleId {
+    /// Creates a [`PuzzleId`] if `day` is one of the days of `year`'s event, returns [`None`]
+    /// otherwise.
+    #[must_use]
+    pub const fn try_new(year: Year, day: Day) -> Option<Self> {
+        if day.into_inner() > year.last_day().into_inner() {
+            return None;
+        }
+
+        Some(PuzzleId { year, day })
+    }
+
+    #[must_use]
+    pub const fn year(self) -> Year {
+        self.year
+    }
+
+    #[must_use]
+    pub const fn day(self) -> Day {
+        self.day
+    }
+}
+
+impl Display for PuzzleId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.year, self.day)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for PuzzleId {
+    type Err = PuzzleIdFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (year, day) = s.split_once('/').ok_or(PuzzleIdFromStrError {})?;
+
+        let year = year.parse().map_err(|_| PuzzleIdFromStrError {})?;
+        let day = day.parse().map_err(|_| PuzzleIdFromStrError {})?;
+
+        Self::try_new(year, day).ok_or(PuzzleIdFromStrError {})
+    }
+}
+
+/// An error which can be returned when parsing a [`PuzzleId`].
+#[expect(clippy::module_name_repetitions, reason = "Name clarity")]
+#[derive(Debug)]
+pub struct PuzzleIdFromStrError {}
+
+impl Error for PuzzleIdFromStrError {}
+
+impl Display for PuzzleIdFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting `YEAR/DAY`, with a day of that year's event")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Serializes as its [`Display`], e.g. `"2024/07"`.
+#[cfg(feature = "serde")]
+impl Serialize for PuzzleId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for PuzzleId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(D::Error::custom)
+    }
+}
+
+/// Creates a [`PuzzleId`] value in a const context.
+#[macro_export]
+macro_rules! puzzle {
+    ($year:literal, $day:literal) => {{
+        $crate::shared::puzzle::PuzzleId::try_new(
+            $crate::shared::year::Year::try_new($year).expect(concat!(
+                "invalid year `",
+                $year,
+                "`, expecting 2015 or later"
+            )),
+            $crate::day!($day),
+        )
+        .expect(concat!(
+            "invalid puzzle `",
+            $year,
+            "/",
+            $day,
+            "`, the event doesn't have that day"
+        ))
+    }};
+}
@@ -1,16 +1,193 @@
-use std::path::Path;
+use std::error::Error;
+use std::fmt::Display;
+use std::io::{self, Read as _};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 use std::{env, fs};
 
-use super::day::Day;
+use super::puzzle::PuzzleId;
 
-fn read_file_base(filepath: impl AsRef<Path>) -> String {
-    let f = fs::read_to_string(&filepath);
-    f.unwrap_or_else(|e| {
-        panic!(
-            "Error reading file \"{}\": {e:?}",
-            filepath.as_ref().to_str().unwrap()
-        )
-    })
+/// The environment variable that, when set, replaces the `data` directory.
+pub const DATA_DIR_VAR: &str = "AOC_DATA_DIR";
+
+/// Where the puzzle input of a day is read from.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub enum Input {
+    /// The day's file in the `inputs` data folder.
+    #[default]
+    Data,
+    /// An explicit file.
+    Path(PathBuf),
+    /// Standard input, selected with `-`.
+    Stdin,
+}
+
+impl FromStr for Input {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "-" {
+            Ok(Input::Stdin)
+        } else {
+            Ok(Input::Path(s.into()))
+        }
+    }
+}
+
+/// An error which can be returned when reading a data file.
+#[derive(Debug)]
+pub enum ReadFileError {
+    /// None of the candidate paths exist.
+    NotFound {
+        puzzle: PuzzleId,
+        tried: Vec<PathBuf>,
+    },
+    /// The file exists, but we're not allowed to read it.
+    PermissionDenied(PathBuf),
+    /// The file's contents aren't valid UTF-8.
+    InvalidUtf8(PathBuf),
+    /// The file has nothing but whitespace in it.
+    Empty(PathBuf),
+    /// Any other I/O error.
+    Io(PathBuf, io::Error),
+}
+
+impl Error for ReadFileError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            ReadFileError::Io(_, ref error) => Some(error),
+            ReadFileError::NotFound { .. }
+            | ReadFileError::PermissionDenied(_)
+            | ReadFileError::InvalidUtf8(_)
+            | ReadFileError::Empty(_) => None,
+        }
+    }
+}
+
+impl Display for ReadFileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            ReadFileError::NotFound { puzzle, ref tried } => {
+                write!(f, "no file found for {}, tried:", puzzle)?;
+
+                for path in tried {
+                    write!(f, "\n  {}", path.display())?;
+                }
+
+                Ok(())
+            },
+            ReadFileError::PermissionDenied(ref path) => {
+                write!(f, "permission denied reading \"{}\"", path.display())
+            },
+            ReadFileError::InvalidUtf8(ref path) => {
+                write!(f, "\"{}\" is not valid UTF-8", path.display())
+            },
+            ReadFileError::Empty(ref path) => write!(f, "\"{}\" is empty", path.display()),
+            ReadFileError::Io(ref path, ref error) => {
+                write!(f, "error reading \"{}\": {}", path.display(), error)
+            },
+        }
+    }
+}
+
+/// The directories that are searched for data files, in order.
+///
+/// When [`DATA_DIR_VAR`] is set that is the only one, otherwise it's `data` in the current
+/// directory, followed by `data` in the root of this crate.
+#[must_use]
+pub fn data_dirs() -> Vec<PathBuf> {
+    if let Some(data_dir) = env::var_os(DATA_DIR_VAR) {
+        return vec![data_dir.into()];
+    }
+
+    let mut data_dirs = vec![];
+
+    if let Ok(cwd) = env::current_dir() {
+        data_dirs.push(cwd.join("data"));
+    }
+
+    let crate_data_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("data");
+
+    if !data_dirs.contains(&crate_data_dir) {
+        data_dirs.push(crate_data_dir);
+    }
+
+    data_dirs
+}
+
+/// Finds `file_name` in the first data directory whose `folder` of the `puzzle`'s year has it.
+fn locate(folder: &str, file_name: &str, puzzle: PuzzleId) -> Result<PathBuf, ReadFileError> {
+    let tried = data_dirs()
+        .into_iter()
+        .map(|data_dir| {
+            data_dir
+                .join(puzzle.year().to_string())
+                .join(folder)
+                .join(file_name)
+        })
+        .collect::<Vec<_>>();
+
+    match tried.iter().find(|path| path.is_file()) {
+        Some(path) => Ok(path.clone()),
+        None => Err(ReadFileError::NotFound { puzzle, tried }),
+    }
+}
+
+/// Turns the raw contents of `path` into a `String`, rejecting files that are empty.
+fn check_contents(path: &Path, bytes: Vec<u8>) -> Result<String, ReadFileError> {
+    let contents =
+        String::from_utf8(bytes).map_err(|_| ReadFileError::InvalidUtf8(path.to_owned()))?;
+
+    if contents.trim().is_empty() {
+        return Err(ReadFileError::Empty(path.to_owned()));
+    }
+
+    Ok(contents)
+}
+
+fn try_read_file_base(filepath: &Path, puzzle: PuzzleId) -> Result<String, ReadFileError> {
+    let bytes = fs::read(filepath).map_err(|error| {
+        let kind = error.kind();
+
+        if kind == io::ErrorKind::NotFound {
+            ReadFileError::NotFound {
+                puzzle,
+                tried: vec![filepath.to_owned()],
+            }
+        } else if kind == io::ErrorKind::PermissionDenied {
+            ReadFileError::PermissionDenied(filepath.to_owned())
+        } else {
+            ReadFileError::Io(filepath.to_owned(), error)
+        }
+    })?;
+
+    check_contents(filepath, bytes)
+}
+
+/// Reads a text file from a data folder of the puzzle's year to a string, e.g.
+/// `data/2024/inputs/07.txt`.
+///
+/// # Errors
+/// When the file does not exist, cannot be read, is not UTF-8 or is empty
+pub fn try_read_file(folder: &str, puzzle: &PuzzleId) -> Result<String, ReadFileError> {
+    let filepath = locate(folder, &format!("{}.txt", puzzle.day()), *puzzle)?;
+
+    try_read_file_base(&filepath, *puzzle)
+}
+
+/// Reads a text file from a data folder of the puzzle's year to a string, appending a part suffix.
+/// E.g. like `01-2.txt`.
+///
+/// # Errors
+/// When the file does not exist, cannot be read, is not UTF-8 or is empty
+pub fn try_read_file_part(
+    folder: &str,
+    puzzle: &PuzzleId,
+    part: u8,
+) -> Result<String, ReadFileError> {
+    let filepath = locate(folder, &format!("{}-{}.txt", puzzle.day(), part), *puzzle)?;
+
+    try_read_file_base(&filepath, *puzzle)
 }
 
 /// Helper function that reads a text file to a string.
@@ -18,11 +195,8 @@ fn read_file_base(filepath: impl AsRef<Path>) -> String {
 ///
 /// if the file does not exist or cannot be read
 #[must_use]
-pub fn read_file(folder: &str, day: &Day) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{}.txt", day));
-
-    read_file_base(filepath)
+pub fn read_file(folder: &str, puzzle: &PuzzleId) -> String {
+    try_read_file(folder, puzzle).unwrap_or_else(|e| panic!("{}", e))
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
@@ -30,27 +204,85 @@ pub fn read_file(folder: &str, day: &Day) -> String {
 ///
 /// if the file does not exist or cannot be read
 #[must_use]
-pub fn read_file_part(folder: &str, day: &Day, part: u8) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd
-        .join("data")
-        .join(folder)
-        .join(format!("{}-{}.txt", day, part));
+pub fn read_file_part(folder: &str, puzzle: &PuzzleId, part: u8) -> String {
+    try_read_file_part(folder, puzzle, part).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Reads the puzzle input of `puzzle` from `input`.
+///
+/// # Errors
+/// When the file does not exist, cannot be read, is not UTF-8 or is empty
+pub fn read_input(input: &Input, puzzle: &PuzzleId) -> Result<String, ReadFileError> {
+    match *input {
+        Input::Data => try_read_file("inputs", puzzle),
+        Input::Path(ref path) => try_read_file_base(path, *puzzle),
+        Input::Stdin => {
+            let stdin = Path::new("-");
+
+            let mut bytes = vec![];
 
-    read_file_base(filepath)
+            io::stdin()
+                .read_to_end(&mut bytes)
+                .map_err(|error| ReadFileError::Io(stdin.to_owned(), error))?;
+
+            check_contents(stdin, bytes)
+        },
+    }
 }
 
+/// Declares the `PUZZLE` of the solution in the invoking `day_NN.rs` file, a `main` for its
+/// binary and the `Solution` struct to implement [`Parts`](crate::shared::Parts) or
+/// [`TryParts`](crate::shared::TryParts) on.
+///
+/// The day comes from the file name, the year from the innermost module or crate whose name ends
+/// with one, see [`Year::infer`](crate::shared::year::Year::infer).
+///
+/// The solution's [`Metadata`](crate::shared::metadata::Metadata) is declared as optional fields,
+/// in any order, and queried through [`Describe`](crate::shared::metadata::Describe):
+///
+/// ```ignore
+/// solution!(
+///     title: "Reindeer Maze",
+///     tags: [Grids, Pathfinding],
+///     complexity: "O(n log n)",
+///     uses: [grids],
+///     status: Partial,
+/// );
+/// ```
+///
+/// The known answers aren't part of the solution, they're read from the
+/// [answers file](crate::shared::answers::Answers).
 #[macro_export]
 macro_rules! solution {
-    () => {
-        $crate::solution!(PartSolution::None, PartSolution::None);
+    (@title $title:literal) => {
+        Some($title)
     };
-    ($solution_1:expr) => {
-        $crate::solution!($solution_1, PartSolution::None);
+    (@tags [$($tag:ident),* $(,)?]) => {
+        &[$($crate::shared::tag::Tag::$tag),*]
     };
-    ($solution_1:expr, $solution_2:expr) => {
-        /// The current day.
-        static DAY: std::sync::LazyLock<$crate::shared::day::Day> =
+    (@complexity $complexity:literal) => {
+        Some($complexity)
+    };
+    (@uses [$($module:ident),* $(,)?]) => {
+        &[$({
+            #[expect(unused_imports, reason = "Only checks that the module exists")]
+            use $crate::shared::$module as _;
+
+            stringify!($module)
+        }),*]
+    };
+    (@status $status:ident) => {
+        Some($crate::shared::metadata::Status::$status)
+    };
+    ($($key:ident: $value:tt),* $(,)?) => {
+        /// What the solution tells about itself.
+        pub const METADATA: $crate::shared::metadata::Metadata = $crate::shared::metadata::Metadata {
+            $($key: $crate::solution!(@$key $value),)*
+            ..$crate::shared::metadata::Metadata::DEFAULT
+        };
+
+        /// The current puzzle.
+        pub static PUZZLE: std::sync::LazyLock<$crate::shared::puzzle::PuzzleId> =
             std::sync::LazyLock::new(|| {
                 use std::path::Path;
 
@@ -61,26 +293,100 @@ macro_rules! solution {
                     .to_str()
                     .expect("Invalid str");
 
-                std::str::FromStr::from_str(file_stem).expect("Could not convert input to Day")
+                let day = file_stem
+                    .strip_prefix("day_")
+                    .expect("Solution files are named `day_NN.rs`");
+
+                let year = $crate::shared::year::Year::infer(module_path!())
+                    .expect("The crate or a module has a name that ends with the year");
+
+                $crate::shared::puzzle::PuzzleId::try_new(
+                    year,
+                    std::str::FromStr::from_str(day).expect("Could not convert input to Day"),
+                )
+                .expect("The event has the day")
             });
 
-        fn main() {
-            // use advent_of_code_2024::template::runner::*;
-            let input = $crate::shared::solution::read_file("inputs", &DAY);
+        /// Solves both parts and checks them against the known answers.
+        #[must_use]
+        pub fn main() -> std::process::ExitCode {
+            $crate::runner::day_main(*PUZZLE, &Solution {})
+        }
+
+        pub struct Solution {}
+
+        impl $crate::shared::metadata::Describe for Solution {
+            fn metadata(&self) -> &'static $crate::shared::metadata::Metadata {
+                &METADATA
+            }
+        }
+    };
+}
 
-            let part_1_expected_solution: PartSolution = PartSolution::from($solution_1);
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use std::{env, fs, process};
 
-            let s = Solution {};
+    use super::{Input, ReadFileError, read_input};
+    use crate::puzzle;
 
-            assert_eq!(part_1_expected_solution, s.part_1(&input));
+    fn temp_file(name: &str, contents: &[u8]) -> PathBuf {
+        let path = env::temp_dir().join(format!("aoc-{}-{}", process::id(), name));
 
-            let part_2_expected_solution: PartSolution = PartSolution::from($solution_2);
+        fs::write(&path, contents).unwrap();
 
-            assert_eq!(part_2_expected_solution, s.part_2(&input));
-            // run_part(part_one, &input, &DAY, 1);
-            // run_part(part_two, &input, &DAY, 2);
-        }
+        path
+    }
 
-        pub struct Solution {}
-    };
+    #[test]
+    fn reads_path() {
+        let path = temp_file("valid.txt", b"1 2 3\n");
+
+        let result = read_input(&Input::Path(path.clone()), &puzzle!(2024, 1));
+
+        fs::remove_file(path).unwrap();
+
+        assert_eq!("1 2 3\n", result.unwrap());
+    }
+
+    #[test]
+    fn not_found() {
+        let path = env::temp_dir().join("aoc-does-not-exist.txt");
+
+        let result = read_input(&Input::Path(path.clone()), &puzzle!(2024, 1));
+
+        assert!(
+            matches!(result, Err(ReadFileError::NotFound { puzzle, ref tried }) if puzzle.day() == 1 && *tried == [path]),
+            "Expected NotFound"
+        );
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        let path = temp_file("invalid.txt", &[0xff, 0xfe, 0xfd]);
+
+        let result = read_input(&Input::Path(path.clone()), &puzzle!(2024, 1));
+
+        fs::remove_file(&path).unwrap();
+
+        assert!(
+            matches!(result, Err(ReadFileError::InvalidUtf8(ref p)) if *p == path),
+            "Expected InvalidUtf8"
+        );
+    }
+
+    #[test]
+    fn empty() {
+        let path = temp_file("empty.txt", b" \n");
+
+        let result = read_input(&Input::Path(path.clone()), &puzzle!(2024, 1));
+
+        fs::remove_file(&path).unwrap();
+
+        assert!(
+            matches!(result, Err(ReadFileError::Empty(ref p)) if *p == path),
+            "Expected Empty"
+        );
+    }
 }
@@ -0,0 +1,99 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// What a solution is about, or what it uses, to select related days at once. A day declares its
+/// tags in [`solution!`](crate::solution).
+///
+/// # Display
+/// This value displays as its lowercase name, the same one it's parsed from.
+///
+/// ```
+/// # use advent_of_code_2024::shared::tag::Tag;
+/// assert_eq!(Tag::DynamicProgramming.to_string(), "dynamic-programming");
+/// assert_eq!("grids".parse::<Tag>().unwrap(), Tag::Grids);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Tag {
+    /// Uses [`Grid`](crate::shared::grids::grid::Grid).
+    Grids,
+    /// Searches for shortest paths, or any path at all.
+    Pathfinding,
+    /// Works on nodes and the edges between them.
+    Graphs,
+    /// Follows the puzzle's rules step by step.
+    Simulation,
+    /// Solves equations, or otherwise needs a formula.
+    Math,
+    /// Most of the work is reading the input.
+    Parsing,
+    /// Caches the answers to smaller problems.
+    DynamicProgramming,
+}
+
+impl Tag {
+    /// Every tag, in order.
+    pub const ALL: [Tag; 7] = [
+        Tag::Grids,
+        Tag::Pathfinding,
+        Tag::Graphs,
+        Tag::Simulation,
+        Tag::Math,
+        Tag::Parsing,
+        Tag::DynamicProgramming,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            Tag::Grids => "grids",
+            Tag::Pathfinding => "pathfinding",
+            Tag::Graphs => "graphs",
+            Tag::Simulation => "simulation",
+            Tag::Math => "math",
+            Tag::Parsing => "parsing",
+            Tag::DynamicProgramming => "dynamic-programming",
+        }
+    }
+}
+
+impl Display for Tag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Tag {
+    type Err = TagFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Tag::ALL
+            .into_iter()
+            .find(|tag| tag.name() == s)
+            .ok_or(TagFromStrError {})
+    }
+}
+
+/// An error which can be returned when parsing a [`Tag`].
+#[expect(clippy::module_name_repetitions, reason = "Name clarity")]
+#[derive(Debug)]
+pub struct TagFromStrError {}
+
+impl Error for TagFromStrError {}
+
+impl Display for TagFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting one of ")?;
+
+        for (index, tag) in Tag::ALL.iter().enumerate() {
+            if index > 0 {
+                f.write_str(", ")?;
+            }
+
+            write!(f, "`{}`", tag)?;
+        }
+
+        Ok(())
+    }
+}
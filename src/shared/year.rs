@@ -0,0 +1,181 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[cfg(feature = "serde")]
+use serde::de::Error as _;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::day::Day;
+
+/// The year of an Advent of Code event, 2015 or later.
+///
+/// # Display
+/// This value displays as its four digits.
+///
+/// ```
+/// # use advent_of_code_2024::shared::year::Year;
+/// const YEAR: Year = Year::try_new(2025).unwrap();
+/// assert_eq!(YEAR.to_string(), "2025");
+/// assert_eq!(YEAR.last_day(), 12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// The year of this crate's event, from the number its name ends with.
+    pub const DEFAULT: Year = match trailing_number(env!("CARGO_PKG_NAME")) {
+        Some(year) => match Year::try_new(year) {
+            Some(year) => year,
+            None => panic!("The crate's name ends with a year before 2015"),
+        },
+        None => panic!("The crate's name doesn't end with a year"),
+    };
+
+    /// Creates a [`Year`] from the provided value if there was an event that year or later,
+    /// returns [`None`] otherwise.
+    #[must_use]
+    pub const fn try_new(year: u16) -> Option<Self> {
+        if year < 2015 {
+            return None;
+        }
+
+        Some(Year(year))
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    #[must_use]
+    pub const fn into_inner(self) -> u16 {
+        self.0
+    }
+
+    /// The last day of the event: the 25th until 2024, the 12th from 2025 on.
+    #[must_use]
+    pub const fn last_day(self) -> Day {
+        if self.0 < 2025 {
+            Day::new_unchecked(25)
+        } else {
+            Day::new_unchecked(12)
+        }
+    }
+
+    /// The year of the module at `module_path`, as given by [`module_path!`]: the innermost
+    /// module, or crate, whose name ends with a year. E.g. 2025 for `aoc::y2025::day_01` and
+    /// 2024 for `advent_of_code_2024::days::day_07`.
+    #[must_use]
+    pub fn infer(module_path: &str) -> Option<Year> {
+        module_path
+            .rsplit("::")
+            .filter_map(trailing_number)
+            .find_map(Year::try_new)
+    }
+}
+
+/// The number at the end of `name`, if any.
+const fn trailing_number(name: &str) -> Option<u16> {
+    let bytes = name.as_bytes();
+
+    let mut start = bytes.len();
+
+    while start > 0 && bytes[start - 1].is_ascii_digit() {
+        start -= 1;
+    }
+
+    if start == bytes.len() {
+        return None;
+    }
+
+    let mut number: u16 = 0;
+
+    while start < bytes.len() {
+        let Some(shifted) = number.checked_mul(10) else {
+            return None;
+        };
+
+        let Some(added) = shifted.checked_add((bytes[start] - b'0') as u16) else {
+            return None;
+        };
+
+        number = added;
+        start += 1;
+    }
+
+    Some(number)
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| YearFromStrError {})?;
+        Self::try_new(year).ok_or(YearFromStrError {})
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[expect(clippy::module_name_repetitions, reason = "Name clarity")]
+#[derive(Debug)]
+pub struct YearFromStrError {}
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting the year of an event, 2015 or later")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Serializes as its number.
+#[cfg(feature = "serde")]
+impl Serialize for Year {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u16(self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Year {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let year = u16::deserialize(deserializer)?;
+
+        Self::try_new(year).ok_or_else(|| D::Error::custom(YearFromStrError {}))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Year;
+
+    #[test]
+    fn infer() {
+        assert_eq!(
+            Year::try_new(2024),
+            Year::infer("advent_of_code_2024::days::day_07")
+        );
+        assert_eq!(
+            Year::try_new(2025),
+            Year::infer("advent_of_code_2024::days::y2025::day_01")
+        );
+        assert_eq!(Year::try_new(2015), Year::infer("aoc::year_2015::day_25"));
+        assert_eq!(None, Year::infer("aoc::days::day_07"));
+        assert_eq!(Year::try_new(2024), Some(Year::DEFAULT));
+    }
+
+    #[test]
+    fn last_day() {
+        assert_eq!(Year::try_new(2015).unwrap().last_day(), 25);
+        assert_eq!(Year::try_new(2024).unwrap().last_day(), 25);
+        assert_eq!(Year::try_new(2025).unwrap().last_day(), 12);
+    }
+}
@@ -0,0 +1,31 @@
+use hashbrown::{HashMap, HashSet};
+
+use crate::shared::{PartSolution, Parts};
+
+crate::solution!(title: "TITLE", tags: [Graphs]);
+
+/// The nodes each node has an edge to, from lines like `a-b`.
+fn parse_input(input: &str) -> HashMap<&str, HashSet<&str>> {
+    let mut graph: HashMap<&str, HashSet<&str>> = HashMap::new();
+
+    for (from, to) in input.lines().filter_map(|line| line.split_once('-')) {
+        graph.entry(from).or_default().insert(to);
+        graph.entry(to).or_default().insert(from);
+    }
+
+    graph
+}
+
+impl Parts for Solution {
+    fn part_1(&self, input: &str) -> PartSolution {
+        let _graph = parse_input(input);
+
+        None.into()
+    }
+
+    fn part_2(&self, input: &str) -> PartSolution {
+        let _graph = parse_input(input);
+
+        None.into()
+    }
+}
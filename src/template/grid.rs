@@ -0,0 +1,31 @@
+use crate::shared::error::SolveError;
+use crate::shared::grids::grid::Grid;
+use crate::shared::{PartSolution, TryParts};
+
+crate::solution!(title: "TITLE", tags: [Grids], uses: [grids]);
+
+impl TryParts for Solution {
+    type Parsed<'input> = Grid<char>;
+
+    type Params = ();
+
+    fn parse<'input>(&self, input: &'input str) -> Result<Self::Parsed<'input>, SolveError> {
+        input.parse()
+    }
+
+    fn try_part_1_parsed(
+        &self,
+        _grid: &Self::Parsed<'_>,
+        _params: &Self::Params,
+    ) -> Result<PartSolution, SolveError> {
+        Ok(None.into())
+    }
+
+    fn try_part_2_parsed(
+        &self,
+        _grid: &Self::Parsed<'_>,
+        _params: &Self::Params,
+    ) -> Result<PartSolution, SolveError> {
+        Ok(None.into())
+    }
+}
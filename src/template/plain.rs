@@ -0,0 +1,13 @@
+use crate::shared::{PartSolution, Parts};
+
+crate::solution!(title: "TITLE");
+
+impl Parts for Solution {
+    fn part_1(&self, _input: &str) -> PartSolution {
+        None.into()
+    }
+
+    fn part_2(&self, _input: &str) -> PartSolution {
+        None.into()
+    }
+}
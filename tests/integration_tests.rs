@@ -1,15 +1,182 @@
-#![expect(clippy::tests_outside_test_module, reason = "Integration tests")]
+//! Runs every day against its input and every example against the expected answers in the data
+//! directory, one test per year, day, part and file. Adding an example is a matter of dropping
+//! `<YEAR>/examples/NN-K.txt` in the data directory and adding its answers to
+//! `<YEAR>/examples/answers.toml`.
 
-#[test]
-fn assert_world_ok() {
-    let cls1 = || true;
-    let cls2 = || true;
-    assert_eq!(cls1(), cls2());
+use std::collections::BTreeSet;
+use std::fs;
+use std::process::ExitCode;
+
+use advent_of_code_2024::shared::PartSolution;
+use advent_of_code_2024::shared::answers::{Answers, Example};
+use advent_of_code_2024::shared::day::Day;
+use advent_of_code_2024::shared::params::Param;
+use advent_of_code_2024::shared::part::Part;
+use advent_of_code_2024::shared::puzzle::PuzzleId;
+use advent_of_code_2024::shared::solution::{ReadFileError, try_read_file, try_read_file_part};
+use advent_of_code_2024::shared::year::Year;
+use advent_of_code_2024::{days, runner};
+use libtest_mimic::{Arguments, Failed, Trial};
+
+fn main() -> ExitCode {
+    let arguments = Arguments::from_args();
+
+    let mut trials = vec![];
+
+    let years = days::all()
+        .map(|(puzzle, _)| puzzle.year())
+        .collect::<BTreeSet<_>>();
+
+    for year in years {
+        match inputs(year) {
+            Ok(inputs) => trials.extend(inputs),
+            Err(error) => trials.push(Trial::test(format!("{}::inputs", year), move || {
+                Err(error.into())
+            })),
+        }
+
+        match examples(year) {
+            Ok(examples) => trials.extend(examples),
+            Err(error) => trials.push(Trial::test(format!("{}::examples", year), move || {
+                Err(error.into())
+            })),
+        }
+    }
+
+    libtest_mimic::run(&arguments, trials).exit_code()
+}
+
+/// A test per day of `year` and part. A part without a known answer, like the last day's second
+/// part, has to have no answer either.
+fn inputs(year: Year) -> Result<Vec<Trial>, String> {
+    let answers = Answers::<Day>::load(&Answers::<Day>::path(year)).map_err(|e| e.to_string())?;
+
+    let mut trials = vec![];
+
+    for (puzzle, _) in days::all().filter(|&(puzzle, _)| puzzle.year() == year) {
+        for part in Part::ALL {
+            let expected = answers
+                .get(puzzle.day(), part)
+                .cloned()
+                .unwrap_or(PartSolution::None);
+
+            let name = format!("{}::day_{}::part_{}::outcome", year, puzzle.day(), part);
+
+            let trial = Trial::test(name, move || {
+                check(
+                    puzzle,
+                    part,
+                    try_read_file("inputs", &puzzle),
+                    &[],
+                    &expected,
+                )
+            });
+
+            trials.push(trial);
+        }
+    }
+
+    Ok(trials)
 }
 
-#[test]
-fn assert_world_ok2() {
-    let cls1 = || false;
-    let cls2 = || false;
-    assert_eq!(cls1(), cls2());
+/// A test per example file of `year` and part with an expected answer, solved with the example's
+/// parameters. Examples without any expected answer are ignored.
+fn examples(year: Year) -> Result<Vec<Trial>, String> {
+    let path = Answers::<Example>::path(year);
+
+    let answers = Answers::<Example>::load(&path).map_err(|e| e.to_string())?;
+
+    let mut examples = answers
+        .iter()
+        .map(|(example, _, _)| example)
+        .collect::<BTreeSet<_>>();
+
+    if let Some(directory) = path.parent().filter(|directory| directory.is_dir()) {
+        for entry in fs::read_dir(directory).map_err(|e| e.to_string())? {
+            let file = entry.map_err(|e| e.to_string())?.path();
+
+            if file.extension().is_some_and(|extension| extension == "txt") {
+                if let Some(example) = file
+                    .file_stem()
+                    .and_then(|stem| stem.to_str())
+                    .and_then(|stem| stem.parse::<Example>().ok())
+                {
+                    examples.insert(example);
+                }
+            }
+        }
+    }
+
+    let mut trials = vec![];
+
+    for example in examples {
+        let Some(puzzle) = PuzzleId::try_new(year, example.day) else {
+            return Err(format!("{} has no day {}", year, example.day));
+        };
+
+        let test = match example.variant {
+            Some(variant) => format!("example_{}", variant),
+            None => String::from("example"),
+        };
+
+        let expected = Part::ALL.map(|part| answers.get(example, part).cloned());
+
+        let params = answers.params(example).to_vec();
+
+        let unknown = expected.iter().all(Option::is_none);
+
+        for (part, expected) in Part::ALL.into_iter().zip(expected) {
+            if expected.is_none() && !unknown {
+                continue;
+            }
+
+            let name = format!("{}::day_{}::part_{}::{}", year, example.day, part, test);
+
+            let params = params.clone();
+
+            let trial = Trial::test(name, move || {
+                let input = match example.variant {
+                    Some(variant) => try_read_file_part("examples", &puzzle, variant),
+                    None => try_read_file("examples", &puzzle),
+                };
+
+                check(
+                    puzzle,
+                    part,
+                    input,
+                    &params,
+                    &expected.unwrap_or(PartSolution::None),
+                )
+            });
+
+            trials.push(trial.with_ignored_flag(unknown));
+        }
+    }
+
+    Ok(trials)
+}
+
+fn check(
+    puzzle: PuzzleId,
+    part: Part,
+    input: Result<String, ReadFileError>,
+    params: &[Param],
+    expected: &PartSolution,
+) -> Result<(), Failed> {
+    let solution = days::get(puzzle).ok_or_else(|| format!("{} is not solved", puzzle))?;
+
+    let input = input.map_err(|e| e.to_string())?;
+
+    let (_, answers) =
+        runner::solve(solution, &input, params, &[part]).map_err(|e| e.to_string())?;
+
+    for answer in answers {
+        let actual = answer.answer.map_err(|e| e.to_string())?;
+
+        if *expected != actual {
+            return Err(format!("expected {}, found {}", expected, actual).into());
+        }
+    }
+
+    Ok(())
 }